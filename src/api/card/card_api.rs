@@ -1,25 +1,48 @@
 use crate::api::card::filter::CardFilter;
-use crate::api::card::filtertypes::CardResponseField;
+use crate::api::card::filtertypes::{CardLanguage, CardResponseField};
+use crate::api::card::validation::FilterDiagnostic;
+use crate::api::catalog::Catalog;
 use crate::api::error::MtgApiErrorKind;
+use crate::api::format::format_api::FormatApi;
+use crate::api::types::type_api::{SubtypeApi, SupertypeApi, TypeApi};
 use failure::Error;
 use failure::ResultExt;
 use reqwest::Client;
 
 use crate::api::response::ApiResponse;
 use crate::model::card::CardDetail;
-use std::rc::Weak;
+use crate::model::ids::{CardId, CardLookup, FoundCard};
+use crate::model::localized::ForeignNameIndex;
+use crate::model::multiface::MultiFaceCard;
+use crate::model::resolver::{CardNameIndex, MatchKind, NameCandidate};
+use futures::future;
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::{Rc, Weak};
 
 use crate::api::util;
 
+/// The default maximum length of the request urls of a ChunkedCardsRequest
+pub const DEFAULT_MAX_URL_LENGTH: usize = 2000;
+
+/// Room reserved in the request urls for the page and page size parameters
+const PAGING_PARAMS_LENGTH: usize = 36;
+
 ///Responsible for the calls to the /cards endpoint
 pub struct CardApi {
     client: Weak<Client>,
     url: String,
+    catalog: RefCell<Option<Rc<Catalog>>>,
 }
 
 impl CardApi {
     pub(crate) fn new(client: Weak<Client>, url: String) -> CardApi {
-        CardApi { client, url }
+        CardApi {
+            client,
+            url,
+            catalog: RefCell::new(None),
+        }
     }
 
     /// Returns a Request Object to fetch all cards
@@ -34,14 +57,226 @@ impl CardApi {
         AllCardsRequest::new_filtered(self.client.clone(), &self.url, 100, filter)
     }
 
-    /// Returns a specific card by a specific id
-    pub async fn find(&self, id: u32) -> Result<ApiResponse<CardDetail>, Error> {
-        let url = [&self.url, "/cards/", &id.to_string()].join("");
+    /// Returns a Request Object to fetch all cards with a filter,
+    /// which is split into several requests if the request url would get too long
+    #[allow(dead_code)]
+    pub fn all_filtered_chunked(&self, filter: CardFilter) -> Box<ChunkedCardsRequest> {
+        ChunkedCardsRequest::new(self.client.clone(), &self.url, 100, filter)
+    }
+
+    /// Returns the catalog of types, subtypes, supertypes and formats used to validate filters
+    ///
+    /// The catalog is fetched with the first call and cached afterwards
+    #[allow(dead_code)]
+    pub async fn catalog(&self) -> Result<Rc<Catalog>, Error> {
+        if let Some(catalog) = self.catalog.borrow().as_ref() {
+            return Ok(catalog.clone());
+        }
+        let catalog = Rc::new(
+            Catalog::fetch_from(
+                &TypeApi::new(self.client.clone(), self.url.clone()),
+                &SubtypeApi::new(self.client.clone(), self.url.clone()),
+                &SupertypeApi::new(self.client.clone(), self.url.clone()),
+                &FormatApi::new(self.client.clone(), self.url.clone()),
+            )
+            .await?,
+        );
+        self.catalog.replace(Some(catalog.clone()));
+        Ok(catalog)
+    }
+
+    /// Checks the filter against the (cached) catalog of values known by the API
+    #[allow(dead_code)]
+    pub async fn validate(&self, filter: &CardFilter) -> Result<Vec<FilterDiagnostic>, Error> {
+        Ok(filter.lint(&*self.catalog().await?))
+    }
+
+    /// Returns a Request Object to fetch all cards with a filter,
+    /// after checking the filter against the (cached) catalog of values known by the API
+    ///
+    /// # Errors
+    ///
+    /// If the catalog can't be fetched or the filter contains unknown keys or values,
+    /// it will return an error.
+    #[allow(dead_code)]
+    pub async fn all_filtered_validated(
+        &self,
+        filter: CardFilter,
+    ) -> Result<Box<AllCardsRequest>, Error> {
+        let diagnostics = self.validate(&filter).await?;
+        if !diagnostics.is_empty() {
+            return Err(MtgApiErrorKind::InvalidFilter {
+                cause: diagnostics.iter().join("; "),
+            }
+            .into());
+        }
+        Ok(self.all_filtered(filter))
+    }
+
+    /// Fetches the missing faces of the card listed in its `names` and combines all faces
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn multi_face(&self, card: CardDetail) -> Result<MultiFaceCard, Error> {
+        if card.names.len() <= 1 {
+            return Ok(MultiFaceCard::single(card));
+        }
+        let missing: Vec<&String> = card
+            .names
+            .iter()
+            .filter(|name| **name != card.name)
+            .collect();
+        let filter = CardFilter::builder()
+            .names(&missing)
+            .set(card.set.as_str())
+            .build();
+        let mut request = self.all_filtered(filter);
+        let mut faces = vec![card.clone()];
+        loop {
+            let response = request.next_page().await?;
+            if response.content.is_empty() {
+                break;
+            }
+            faces.extend(
+                response
+                    .content
+                    .into_iter()
+                    .filter(|face| card.names.contains(&face.name) && face.names == card.names),
+            );
+        }
+        MultiFaceCard::group(faces)
+            .into_iter()
+            .find(|grouped| grouped.faces().iter().any(|face| face.id == card.id))
+            .ok_or_else(|| MtgApiErrorKind::CardNotFound.into())
+    }
+
+    /// Returns a specific card by its id or its multiverse id
+    ///
+    /// The response records which kind of id the card was looked up by.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the request fails or no card has the id.
+    pub async fn find<T>(&self, id: T) -> Result<ApiResponse<FoundCard>, Error>
+    where
+        T: Into<CardLookup>,
+    {
+        let lookup = id.into();
+        let url = [&self.url, "/cards/", &lookup.to_string()].join("");
         let mut response = util::send_response(&url, &self.client).await?;
         let headers = std::mem::take(response.headers_mut());
         let body = response.text().await.context(MtgApiErrorKind::BodyReadError)?;
         let card = util::retrieve_card_from_body(&body)?;
-        Ok(ApiResponse::new(*card, headers))
+        let found = FoundCard {
+            lookup,
+            card: *card,
+        };
+        Ok(ApiResponse::new(found, headers))
+    }
+
+    /// Returns the English card with the foreign name in the language
+    ///
+    /// The API matches foreign names partially, the response only holds the card
+    /// with exactly this name (ignoring the case), if there is one.
+    /// The pages are fetched until the card is found or all partial matches are checked.
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn find_by_foreign_name(
+        &self,
+        name: &str,
+        language: CardLanguage,
+    ) -> Result<ApiResponse<Vec<CardDetail>>, Error> {
+        let filter = CardFilter::builder()
+            .name_with_language(name, language.clone())
+            .build();
+        let mut request = self.all_filtered(filter);
+        let mut index = ForeignNameIndex::default();
+        loop {
+            let mut response = request.next_page().await?;
+            let content = std::mem::take(&mut response.content);
+            let finished = content.is_empty();
+            content.into_iter().for_each(|card| index.insert(card));
+            let found = index.resolve_in(name, &language);
+            if finished || found.is_some() {
+                response.content = found.cloned().into_iter().collect();
+                response.count = Some(response.content.len() as u32);
+                return Ok(response);
+            }
+        }
+    }
+
+    /// Resolves messy user input like `lightning blot` or `Fire/Ice` to ranked candidates
+    ///
+    /// The cards partially matching the input are fetched, if there are no good matches,
+    /// the cards matching its longest word are fetched as well.
+    /// Foreign names are searched in the specified languages.
+    /// The queries keep the punctuation and diacritics of the input, only the part before
+    /// a `/` or `//` separator is sent. Every page of the matches is fetched.
+    /// The fetched cards are ranked locally, see `CardNameIndex::resolve`.
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn resolve_name(
+        &self,
+        input: &str,
+        languages: &[CardLanguage],
+    ) -> Result<Vec<NameCandidate>, Error> {
+        let first_part = input.split('/').next().unwrap_or_default().trim();
+        let longest_word = first_part
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+            .max_by_key(|word| word.chars().count())
+            .unwrap_or_default();
+        let mut index = CardNameIndex::default();
+        for query in &[first_part, longest_word] {
+            if query.is_empty() {
+                continue;
+            }
+            let filter = CardFilter::builder().name(*query).build();
+            self.index_all_pages(filter, &mut index).await?;
+            let candidates = index.resolve(input);
+            match candidates.first() {
+                Some(candidate) if candidate.kind != MatchKind::Fuzzy => return Ok(candidates),
+                _ => (),
+            }
+        }
+        for language in languages {
+            if longest_word.is_empty() {
+                break;
+            }
+            let filter = CardFilter::builder()
+                .name_with_language(longest_word, language.clone())
+                .build();
+            self.index_all_pages(filter, &mut index).await?;
+        }
+        Ok(index.resolve(input))
+    }
+
+    async fn index_all_pages(
+        &self,
+        filter: CardFilter,
+        index: &mut CardNameIndex,
+    ) -> Result<(), Error> {
+        let mut request = self.all_filtered(filter);
+        loop {
+            let response = request.next_page().await?;
+            if response.content.is_empty() {
+                return Ok(());
+            }
+            for card in response.content {
+                index.insert(card);
+            }
+        }
     }
 }
 
@@ -153,3 +388,219 @@ impl AllCardsRequest {
         [self.url.as_str(), paged_filter_sized.as_str()].join("?")
     }
 }
+
+/// Request Object to be used to execute requests with very large filters to the API
+///
+/// Filters with long lists of alternatives (e.g. hundreds of names or multiverse ids) exceed the
+/// url length accepted by the server. This request splits such filters into several requests
+/// that stay below the configured url length, executes them and merges the results,
+/// without returning a card (by id) twice.
+#[allow(dead_code)]
+pub struct ChunkedCardsRequest {
+    page: u32,
+    client: Weak<Client>,
+    api_url: String,
+    filter: CardFilter,
+    order_by: CardResponseField,
+    page_size: u32,
+    max_url_length: usize,
+    concurrent: bool,
+    chunks: Option<Vec<Chunk>>,
+    seen: HashSet<CardId>,
+}
+
+struct Chunk {
+    request: Box<AllCardsRequest>,
+    finished: bool,
+}
+
+impl ChunkedCardsRequest {
+    fn new(
+        client: Weak<Client>,
+        api_url: &str,
+        page_size: u32,
+        filter: CardFilter,
+    ) -> Box<ChunkedCardsRequest> {
+        Box::new(ChunkedCardsRequest {
+            page: 1,
+            client,
+            api_url: api_url.to_string(),
+            filter,
+            order_by: CardResponseField::Name,
+            page_size,
+            max_url_length: DEFAULT_MAX_URL_LENGTH,
+            concurrent: false,
+            chunks: None,
+            seen: HashSet::new(),
+        })
+    }
+
+    /// Executes the calls to the API.
+    /// Repeated calls to this method will return the different pages of all the chunks,
+    /// cards already returned by an earlier call or another chunk are left out.
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use mtgapi_client::prelude::*;
+    /// # async fn get_some_cards(names: &[String]) -> Result<(), Box<dyn Error>> {
+    /// let sdk = MtgClient::new(60);
+    /// let mut get_cards_request = sdk.cards().all_filtered_chunked(
+    ///     CardFilter::builder().names(names).build()
+    /// );
+    /// get_cards_request.set_concurrent(true);
+    /// let mut cards = Vec::new();
+    /// loop {
+    ///     let response = get_cards_request.next_page().await?;
+    ///     if response.content.is_empty() {break}
+    ///     cards.extend(response.content);
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// #
+    /// ```
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response of one of the chunks, it will return an error.
+    /// No chunk advances to its next page then, so the call can be repeated.
+    ///
+    #[allow(dead_code)]
+    pub async fn next_page(&mut self) -> Result<ApiResponse<Vec<CardDetail>>, Error> {
+        if self.chunks.is_none() {
+            self.chunks = Some(self.create_chunks());
+        }
+        let concurrent = self.concurrent;
+        let chunks = self.chunks.as_mut().unwrap();
+        let mut last_response: Option<ApiResponse<Vec<CardDetail>>> = None;
+        let mut cards = Vec::new();
+
+        while cards.is_empty() && chunks.iter().any(|chunk| !chunk.finished) {
+            let mut active: Vec<&mut Chunk> =
+                chunks.iter_mut().filter(|chunk| !chunk.finished).collect();
+            let pages: Vec<u32> = active.iter().map(|chunk| chunk.request.page).collect();
+            let responses = if concurrent {
+                future::join_all(active.iter_mut().map(|chunk| chunk.request.next_page()))
+                    .await
+            } else {
+                let mut responses = Vec::with_capacity(active.len());
+                for chunk in active.iter_mut() {
+                    responses.push(chunk.request.next_page().await);
+                }
+                responses
+            };
+            let responses = match responses.into_iter().collect::<Result<Vec<_>, Error>>() {
+                Ok(responses) => responses,
+                Err(error) => {
+                    for (chunk, page) in active.iter_mut().zip(pages) {
+                        chunk.request.set_page(page);
+                    }
+                    return Err(error);
+                }
+            };
+            for (chunk, mut response) in active.into_iter().zip(responses) {
+                let content = std::mem::take(&mut response.content);
+                if content.is_empty() {
+                    chunk.finished = true;
+                }
+                for card in content {
+                    if self.seen.insert(card.id.clone()) {
+                        cards.push(card);
+                    }
+                }
+                last_response = Some(response);
+            }
+        }
+
+        let count = cards.len() as u32;
+        let (ratelimit_limit, ratelimit_remaining) = match last_response {
+            Some(response) => (response.ratelimit_limit, response.ratelimit_remaining),
+            None => (None, None),
+        };
+        Ok(ApiResponse {
+            content: cards,
+            page_size: Some(self.page_size),
+            count: Some(count),
+            total_count: None,
+            ratelimit_limit,
+            ratelimit_remaining,
+        })
+    }
+
+    /// Returns the filters of the single requests the filter is split into
+    #[allow(dead_code)]
+    pub fn chunk_filters(&self) -> Vec<CardFilter> {
+        let url = [self.api_url.as_str(), "cards"].join("/");
+        let max_length = self
+            .max_url_length
+            .saturating_sub(url.len() + 1 + PAGING_PARAMS_LENGTH);
+        self.filter.chunked(max_length)
+    }
+
+    /// Sets the maximum length of the request urls, the filter is split accordingly.
+    /// Setting it restarts the request at the page set with `set_page`
+    #[allow(dead_code)]
+    pub fn set_max_url_length(&mut self, max_url_length: usize) {
+        self.max_url_length = max_url_length;
+        self.chunks = None;
+        self.seen.clear();
+    }
+
+    /// Sets whether the requests of the different chunks are executed concurrently
+    #[allow(dead_code)]
+    pub fn set_concurrent(&mut self, concurrent: bool) {
+        self.concurrent = concurrent;
+    }
+
+    /// Sets the ordering of the cards
+    #[allow(dead_code)]
+    pub fn order_by(&mut self, field: CardResponseField) {
+        self.for_each_request(|request| request.order_by(field.clone()));
+        self.order_by = field;
+    }
+
+    /// Sets the page for the following API calls
+    #[allow(dead_code)]
+    pub fn set_page(&mut self, page: u32) {
+        self.page = page;
+        self.for_each_request(|request| request.set_page(page));
+    }
+
+    /// Sets the page size for the following API calls
+    #[allow(dead_code)]
+    pub fn set_page_size(&mut self, size: u32) {
+        self.page_size = size;
+        self.for_each_request(|request| request.set_page_size(size));
+    }
+
+    fn for_each_request<F>(&mut self, f: F)
+    where
+        F: Fn(&mut AllCardsRequest),
+    {
+        if let Some(chunks) = self.chunks.as_mut() {
+            for chunk in chunks.iter_mut() {
+                f(&mut chunk.request);
+            }
+        }
+    }
+
+    fn create_chunks(&self) -> Vec<Chunk> {
+        self.chunk_filters()
+            .into_iter()
+            .map(|filter| {
+                let mut request = AllCardsRequest::new_filtered(
+                    self.client.clone(),
+                    &self.api_url,
+                    self.page_size,
+                    filter,
+                );
+                request.set_page(self.page);
+                request.order_by(self.order_by.clone());
+                Chunk {
+                    request,
+                    finished: false,
+                }
+            })
+            .collect()
+    }
+}
@@ -1,6 +1,11 @@
 use crate::api::card::filtertypes::*;
+use crate::api::query;
+use crate::api::query::{FilterCombinator, FilterParam, ValueKind};
+use failure::Error;
 use itertools::Itertools;
+use std::convert::TryFrom;
 use std::fmt::Display;
+use std::str::FromStr;
 
 const SEP_AND: &str = ",";
 const SEP_OR: &str = "|";
@@ -1080,7 +1085,29 @@ impl CardFilterBuilder {
 }
 
 /// Wrapper around the filter string to be used for filtered card api requests
-#[derive(PartialEq, Eq, Clone, Debug)]
+///
+/// The filter is (de)serialized as its query string. When deserializing, the values of known keys
+/// are validated, while unknown keys and the query string itself are kept as they are.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// let filter = CardFilter::builder()
+///     .colors_or(&[CardColor::Red, CardColor::Blue])
+///     .build();
+/// let json = serde_json::to_string(&filter).unwrap();
+/// assert_eq!(json, "\"colors=Red|Blue\"");
+/// assert_eq!(serde_json::from_str::<CardFilter>(&json).unwrap(), filter);
+/// assert!(serde_json::from_str::<CardFilter>("\"colors=Purple\"").is_err());
+///
+/// let filter = CardFilter::builder()
+///     .custom("watermark", "Selesnya")
+///     .text("enters, draw")
+///     .build();
+/// let json = serde_json::to_string(&filter).unwrap();
+/// assert_eq!(serde_json::from_str::<CardFilter>(&json).unwrap(), filter);
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct CardFilter(pub String);
 
 impl CardFilter {
@@ -1089,4 +1116,211 @@ impl CardFilter {
     pub fn builder() -> CardFilterBuilder {
         CardFilterBuilder::new()
     }
+
+    /// Parses an existing query string into a CardFilter
+    ///
+    /// Every key has to be a known filter key and the values of keys backed by
+    /// a filter type (e.g. `colors` or `rarity`) have to be known values of that type.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = CardFilter::parse("colors=Red,Blue&cmc=2&types=Instant").unwrap();
+    /// assert!(filter == CardFilter::builder()
+    ///     .colors_and(&[CardColor::Red, CardColor::Blue])
+    ///     .converted_mana_cost(2)
+    ///     .cardtype(CardType::Instant)
+    ///     .build());
+    ///
+    /// assert!(CardFilter::parse("colour=Red").is_err());
+    /// assert!(CardFilter::parse("rarity=Legendary").is_err());
+    /// assert!(CardFilter::parse("cmc=two").is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<CardFilter, Error> {
+        let params = query::parse_validated(query, value_kind)?;
+        Ok(CardFilter(query::join_params(&params)))
+    }
+
+    /// Returns the structured parameters of the filter
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = CardFilter::builder()
+    ///     .name("Shock")
+    ///     .rarities(&[CardRarity::Common, CardRarity::Uncommon])
+    ///     .build();
+    /// let params = filter.params();
+    /// assert_eq!(params[1].key, "rarity");
+    /// assert_eq!(params[1].values, vec!["Common", "Uncommon"]);
+    /// assert_eq!(params[1].combinator, FilterCombinator::Or);
+    /// ```
+    pub fn params(&self) -> Vec<FilterParam> {
+        query::parse_params(&self.0)
+    }
+
+    /// Creates a CardFilterBuilder from this filter, to extend it with further parameters
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = CardFilter::parse("name=Shock").unwrap()
+    ///     .into_builder()
+    ///     .set("M19")
+    ///     .build();
+    /// assert!(filter == CardFilter("name=Shock&set=M19".to_string()));
+    /// ```
+    pub fn into_builder(self) -> CardFilterBuilder {
+        CardFilterBuilder { filter: self.0 }
+    }
+
+    /// Splits the filter into several filters whose query strings do not exceed the specified length
+    ///
+    /// The length is measured once the query string is percent-encoded for the request url,
+    /// e.g. a space counts as three characters.
+    /// Only parameters with values separated by `|` are split, the other parameters are repeated in every chunk.
+    /// Matching one of the returned filters is equivalent to matching the original one.
+    /// If a filter can't be split any further, it is returned even if it is too long.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = CardFilter::builder()
+    ///     .names(&["Shock", "Mountain", "Island", "Forest"])
+    ///     .set("M19")
+    ///     .build();
+    /// let chunks = filter.chunked(30);
+    /// assert_eq!(chunks, vec![
+    ///     CardFilter("name=Shock|Mountain&set=M19".to_string()),
+    ///     CardFilter("name=Island|Forest&set=M19".to_string()),
+    /// ]);
+    /// assert_eq!(filter.chunked(100), vec![filter]);
+    ///
+    /// let filter = CardFilter::builder()
+    ///     .names(&["Lightning Bolt", "Lava Spike", "Rift Bolt"])
+    ///     .build();
+    /// let chunks = filter.chunked(33);
+    /// assert_eq!(chunks.len(), 2);
+    /// assert!(chunks.iter().all(|chunk| chunk.0.replace(' ', "%20").len() <= 33));
+    /// ```
+    pub fn chunked(&self, max_length: usize) -> Vec<CardFilter> {
+        if query::encoded_len(&self.0) <= max_length {
+            return vec![self.clone()];
+        }
+        let params = self.params();
+        let largest = params
+            .iter()
+            .enumerate()
+            .filter(|(_, param)| {
+                param.combinator == FilterCombinator::Or && param.values.len() > 1
+            })
+            .max_by_key(|(_, param)| query::encoded_len(&param.value()))
+            .map(|(index, _)| index);
+        let index = match largest {
+            Some(index) => index,
+            None => return vec![self.clone()],
+        };
+        let rest = query::encoded_len(&query::join_params(&params))
+            - query::encoded_len(&params[index].value());
+        let budget = max_length.saturating_sub(rest);
+
+        let mut groups: Vec<Vec<String>> = Vec::new();
+        let mut group_length = 0;
+        for value in &params[index].values {
+            let length = query::encoded_len(value);
+            match groups.last_mut() {
+                Some(group) if group_length + SEP_OR.len() + length <= budget => {
+                    group_length += SEP_OR.len() + length;
+                    group.push(value.clone());
+                }
+                _ => {
+                    group_length = length;
+                    groups.push(vec![value.clone()]);
+                }
+            }
+        }
+
+        groups
+            .into_iter()
+            .flat_map(|values| {
+                let mut chunk_params = params.clone();
+                chunk_params[index].values = values;
+                CardFilter(query::join_params(&chunk_params)).chunked(max_length)
+            })
+            .collect()
+    }
+}
+
+impl FromStr for CardFilter {
+    type Err = Error;
+
+    fn from_str(query: &str) -> Result<CardFilter, Error> {
+        CardFilter::parse(query)
+    }
+}
+
+impl TryFrom<String> for CardFilter {
+    type Error = Error;
+
+    /// Validates the values of known keys, unknown keys (e.g. from `custom`) and the raw
+    /// query string are kept as they are
+    fn try_from(query: String) -> Result<CardFilter, Error> {
+        let params: Vec<FilterParam> = query::parse_params(&query)
+            .into_iter()
+            .filter(|param| value_kind(&param.key).is_some())
+            .collect();
+        query::validate_params(&params, value_kind)?;
+        Ok(CardFilter(query))
+    }
+}
+
+impl From<CardFilter> for String {
+    fn from(filter: CardFilter) -> String {
+        filter.0
+    }
+}
+
+/// The keys supported by the cards endpoint
+pub(crate) const FILTER_KEYS: &[&str] = &[
+    "name",
+    "language",
+    "layout",
+    "cmc",
+    "colors",
+    "colorIdentity",
+    "types",
+    "supertypes",
+    "subtypes",
+    "rarity",
+    "set",
+    "setName",
+    "text",
+    "flavor",
+    "artist",
+    "number",
+    "power",
+    "toughness",
+    "loyality",
+    "gameFormat",
+    "legality",
+    "multiverseid",
+    "contains",
+];
+
+pub(crate) fn value_kind(key: &str) -> Option<ValueKind> {
+    match key {
+        "name" | "types" | "subtypes" | "set" | "setName" | "text" | "flavor" | "artist"
+        | "number" | "power" | "toughness" | "loyality" => Some(ValueKind::Text),
+        "cmc" | "multiverseid" => Some(ValueKind::Number),
+        "language" => Some(ValueKind::Known(|value| value.parse::<CardLanguage>().is_ok())),
+        "layout" => Some(ValueKind::Known(|value| value.parse::<CardLayout>().is_ok())),
+        "colors" => Some(ValueKind::Known(|value| value.parse::<CardColor>().is_ok())),
+        "colorIdentity" => Some(ValueKind::Known(|value| {
+            value.parse::<CardColorIdentity>().is_ok()
+        })),
+        "supertypes" => Some(ValueKind::Known(|value| value.parse::<CardSuperType>().is_ok())),
+        "rarity" => Some(ValueKind::Known(|value| value.parse::<CardRarity>().is_ok())),
+        "gameFormat" => Some(ValueKind::Known(|value| value.parse::<GameFormat>().is_ok())),
+        "legality" => Some(ValueKind::Known(|value| value.parse::<CardLegality>().is_ok())),
+        "contains" => Some(ValueKind::Known(|value| {
+            value.parse::<CardResponseField>().is_ok()
+        })),
+        _ => None,
+    }
 }
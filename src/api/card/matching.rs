@@ -0,0 +1,194 @@
+use crate::api::card::filter::CardFilter;
+use crate::api::card::filtertypes::*;
+use crate::api::query::{contains_ignore_case, FilterParam};
+use crate::model::card::CardDetail;
+use crate::model::stat::StatValue;
+use std::borrow::Borrow;
+
+impl CardFilter {
+    /// Evaluates the filter locally against the specified card
+    ///
+    /// The semantics mirror the ones of the API: names, texts, set names and types match partially,
+    /// values separated by `|` need to match one of them and values separated by `,` need to match all of them,
+    /// where `,` binds tighter than `|`.
+    /// A `language` restricts the name match to the foreign names of that language and
+    /// a `legality` restricts the `gameFormat` match to cards with that legality (default: not banned).
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// let card = CardDetail {
+    ///     name: "Lightning Bolt".to_string(),
+    ///     colors: vec![CardColor::Red],
+    ///     type_: "Instant".to_string(),
+    ///     types: vec!["Instant".to_string()],
+    ///     cmc: 1.0,
+    ///     ..Default::default()
+    /// };
+    /// let filter = CardFilter::builder()
+    ///     .name("bolt")
+    ///     .colors_or(&[CardColor::Red, CardColor::Blue])
+    ///     .converted_mana_cost(1)
+    ///     .build();
+    /// assert!(filter.matches(&card));
+    ///
+    /// let filter = CardFilter::builder()
+    ///     .colors_and(&[CardColor::Red, CardColor::Blue])
+    ///     .build();
+    /// assert!(!filter.matches(&card));
+    ///
+    /// let filter = CardFilter::parse("colors=Red,Blue|Red&contains=NAME").unwrap();
+    /// assert!(filter.matches(&card));
+    /// ```
+    pub fn matches(&self, card: &CardDetail) -> bool {
+        let params = self.params();
+        let language = param_value(&params, "language");
+        let legality = param_value(&params, "legality");
+        params.iter().all(|value| match value.key.as_str() {
+            "name" => match language {
+                Some(language) => value.matches(|name| {
+                    card.foreign_names.iter().any(|foreign| {
+                        foreign.language == CardLanguage::from(language)
+                            && contains_ignore_case(&foreign.name, name)
+                    })
+                }),
+                None => value.matches(|name| contains_ignore_case(&card.name, name)),
+            },
+            "gameFormat" => value.matches(|format| {
+                card.legalities.iter().any(|entry| {
+                    entry.format.eq_ignore_ascii_case(format)
+                        && match legality {
+                            Some(legality) => entry.legality == CardLegality::from(legality),
+                            None => entry.legality != CardLegality::Banned,
+                        }
+                })
+            }),
+            "layout" => value.matches(|layout| match &card.layout {
+                Some(card_layout) => {
+                    *card_layout == CardLayout::from(layout)
+                        || contains_ignore_case(card_layout.as_str(), layout)
+                }
+                None => false,
+            }),
+            "cmc" => value.matches(|cmc| match cmc.parse::<f64>() {
+                Ok(cmc) => (card.cmc - cmc).abs() < f64::EPSILON,
+                Err(_) => false,
+            }),
+            "colors" => value.matches(|color| card.colors.contains(&CardColor::from(color))),
+            "colorIdentity" => value.matches(|color| {
+                card.color_identity
+                    .contains(&CardColorIdentity::from(color))
+            }),
+            "types" => value.matches(|type_| {
+                contains_ignore_case(&card.type_, type_) || contains_entry(&card.types, type_)
+            }),
+            "supertypes" => value.matches(|supertype| contains_entry(&card.supertypes, supertype)),
+            "subtypes" => value.matches(|subtype| contains_entry(&card.subtypes, subtype)),
+            "rarity" => value.matches(|rarity| card.rarity == CardRarity::from(rarity)),
+            "set" => value.matches(|set| card.set.eq_ignore_case(set)),
+            "setName" => value.matches(|set_name| match &card.set_name {
+                Some(card_set_name) => contains_ignore_case(card_set_name, set_name),
+                None => false,
+            }),
+            "text" => value.matches(|text| contains_ignore_case(&card.text, text)),
+            "flavor" => value.matches(|flavor| contains_ignore_case(&card.flavor, flavor)),
+            "artist" => value.matches(|artist| contains_ignore_case(&card.artist, artist)),
+            "number" => value.matches(|number| {
+                equals_optional(&card.number.as_ref().map(|number| number.to_string()), number)
+            }),
+            "power" => value.matches(|power| equals_stat(&card.power, power)),
+            "toughness" => value.matches(|toughness| equals_stat(&card.toughness, toughness)),
+            "loyality" | "loyalty" => value.matches(|loyalty| equals_stat(&card.loyalty, loyalty)),
+            "multiverseid" => value.matches(|multiverse_id| {
+                equals_optional(&card.multiverseid.map(|id| id.to_string()), multiverse_id)
+            }),
+            "contains" => value.matches(|field| contains_field(card, field)),
+            _ => true,
+        })
+    }
+
+    /// Evaluates the filter locally against every card and only yields the matching ones
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// let cards = vec![
+    ///     CardDetail { name: "Shock".to_string(), ..Default::default() },
+    ///     CardDetail { name: "Mountain".to_string(), ..Default::default() },
+    ///     CardDetail { name: "Island".to_string(), ..Default::default() },
+    /// ];
+    /// let filter = CardFilter::builder()
+    ///     .names(&["Shock", "Mountain"])
+    ///     .build();
+    /// let names: Vec<&str> = filter.matching(&cards).map(|card| card.name.as_str()).collect();
+    /// assert_eq!(names, vec!["Shock", "Mountain"]);
+    /// ```
+    pub fn matching<'a, I>(&'a self, cards: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator + 'a,
+        I::Item: Borrow<CardDetail>,
+    {
+        cards
+            .into_iter()
+            .filter(move |card| self.matches(card.borrow()))
+    }
+}
+
+fn param_value<'a>(params: &'a [FilterParam], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|param| param.key == key)
+        .and_then(|param| param.values.first())
+        .map(String::as_str)
+}
+
+fn contains_entry(entries: &[String], value: &str) -> bool {
+    entries
+        .iter()
+        .any(|entry| entry.eq_ignore_ascii_case(value))
+}
+
+fn equals_optional(field: &Option<String>, value: &str) -> bool {
+    match field {
+        Some(field) => field.eq_ignore_ascii_case(value),
+        None => false,
+    }
+}
+
+fn equals_stat(field: &Option<StatValue>, value: &str) -> bool {
+    match field {
+        Some(field) => *field == StatValue::from(value),
+        None => false,
+    }
+}
+
+fn contains_field(card: &CardDetail, field: &str) -> bool {
+    use crate::api::card::filtertypes::CardResponseField::*;
+    match CardResponseField::from(field) {
+        Type | Types => !card.types.is_empty(),
+        ColorIdentity => !card.color_identity.is_empty(),
+        ManaCost => card.mana_cost.is_some(),
+        SetName => card.set_name.is_some(),
+        ImageUrl => card.image_url.is_some(),
+        OriginalText => card.original_text.is_some(),
+        OriginalType => card.original_type.is_some(),
+        Name => !card.name.is_empty(),
+        Cmc => true,
+        Colors => !card.colors.is_empty(),
+        Subtypes => !card.subtypes.is_empty(),
+        Rarity => !card.rarity.as_str().is_empty(),
+        Set => !card.set.as_str().is_empty(),
+        Text => !card.text.is_empty(),
+        Artist => !card.artist.is_empty(),
+        Number => card.number.is_some(),
+        Power => card.power.is_some(),
+        Toughness => card.toughness.is_some(),
+        Layout => card.layout.is_some(),
+        MultiverseId => card.multiverseid.is_some(),
+        Rulings => !card.rulings.is_empty(),
+        Printing => !card.printings.is_empty(),
+        Legalities => !card.legalities.is_empty(),
+        Id => !card.id.as_str().is_empty(),
+        Other(_) => false,
+    }
+}
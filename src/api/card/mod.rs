@@ -1,3 +1,5 @@
 pub mod card_api;
 pub mod filter;
 pub mod filtertypes;
+mod matching;
+pub mod validation;
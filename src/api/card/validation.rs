@@ -0,0 +1,225 @@
+use crate::api::card::filter::{value_kind, CardFilter, FILTER_KEYS};
+use crate::api::card::filtertypes::*;
+use crate::api::catalog::Catalog;
+use crate::api::query::ValueKind;
+use crate::api::util;
+use std::fmt;
+use std::fmt::Display;
+
+/// The kind of problem found in a filter
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum DiagnosticKind {
+    /// The key is not supported by the API
+    UnknownKey,
+    /// The value is not known by the API, the filter won't match any card
+    UnknownValue,
+    /// The value has the wrong format, e.g. a text where a number is expected
+    InvalidValue,
+}
+
+/// A problem found in a filter, with suggestions for what might have been meant
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FilterDiagnostic {
+    pub kind: DiagnosticKind,
+    pub key: String,
+    pub value: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+impl Display for FilterDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.kind, &self.value) {
+            (DiagnosticKind::UnknownKey, _) | (_, None) => {
+                write!(f, "unknown filter key '{}'", self.key)?
+            }
+            (DiagnosticKind::UnknownValue, Some(value)) => {
+                write!(f, "unknown value '{}' for filter '{}'", value, self.key)?
+            }
+            (DiagnosticKind::InvalidValue, Some(value)) => {
+                write!(f, "invalid value '{}' for filter '{}'", value, self.key)?
+            }
+        }
+        if !self.suggestions.is_empty() {
+            write!(f, ", did you mean '{}'?", self.suggestions.join("' or '"))?;
+        }
+        Ok(())
+    }
+}
+
+impl CardFilter {
+    /// Checks the filter against the catalog of values known by the API
+    ///
+    /// Returns a diagnostic for every unknown key and every value that won't match any card,
+    /// together with suggestions for similar known keys and values.
+    /// Catalog lists that are empty are not checked, except for game formats,
+    /// which are checked against the formats known to this version of the client then.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let catalog = Catalog::from_values(
+    ///     &["Creature".to_string(), "Instant".to_string()],
+    ///     &["Goblin".to_string(), "Elf".to_string()],
+    ///     &["Legendary".to_string()],
+    ///     &["Standard".to_string()],
+    /// );
+    /// let filter = CardFilter::builder()
+    ///     .subtype("Goblim")
+    ///     .fulltype("Legendary Creatur")
+    ///     .custom("colour", "Red")
+    ///     .build();
+    /// let diagnostics = filter.lint(&catalog);
+    /// assert_eq!(diagnostics.len(), 3);
+    /// assert_eq!(diagnostics[0].to_string(), "unknown value 'Goblim' for filter 'subtypes', did you mean 'Goblin'?");
+    /// assert_eq!(diagnostics[1].suggestions, vec!["Creature"]);
+    /// assert_eq!(diagnostics[2].to_string(), "unknown filter key 'colour', did you mean 'colors'?");
+    ///
+    /// let filter = CardFilter::builder().subtype("Elf").build();
+    /// assert!(filter.lint(&catalog).is_empty());
+    ///
+    /// let filter = CardFilter::builder().game_format(GameFormat::Modern).build();
+    /// assert_eq!(filter.lint(&catalog)[0].value, Some("Modern".to_string()));
+    /// assert!(filter.lint(&Catalog::default()).is_empty());
+    /// ```
+    pub fn lint(&self, catalog: &Catalog) -> Vec<FilterDiagnostic> {
+        let mut diagnostics = Vec::new();
+        for param in self.params() {
+            let key = param.key.as_str();
+            let kind = match value_kind(key) {
+                Some(kind) => kind,
+                None => {
+                    diagnostics.push(FilterDiagnostic {
+                        kind: DiagnosticKind::UnknownKey,
+                        key: param.key.clone(),
+                        value: None,
+                        suggestions: util::suggestions(key, FILTER_KEYS.iter().cloned()),
+                    });
+                    continue;
+                }
+            };
+            for value in param.terms() {
+                let diagnostic = match key {
+                    "types" => check_type_line(catalog, value),
+                    "subtypes" => {
+                        check_catalog(value, &catalog.subtypes, |subtype| subtype.as_str())
+                    }
+                    "supertypes" => {
+                        check_catalog(value, &catalog.supertypes, |value| value.as_str())
+                    }
+                    "gameFormat" if !catalog.formats.is_empty() => {
+                        check_catalog(value, &catalog.formats, |value| value.as_str())
+                    }
+                    _ => check_kind(key, kind, value),
+                };
+                if let Some(mut diagnostic) = diagnostic {
+                    diagnostic.key = param.key.clone();
+                    diagnostics.push(diagnostic);
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+fn unknown_value(value: &str, suggestions: Vec<String>) -> Option<FilterDiagnostic> {
+    Some(FilterDiagnostic {
+        kind: DiagnosticKind::UnknownValue,
+        key: String::new(),
+        value: Some(value.to_string()),
+        suggestions,
+    })
+}
+
+fn check_catalog<T, F>(value: &str, known: &[T], as_str: F) -> Option<FilterDiagnostic>
+where
+    F: Fn(&T) -> &str,
+{
+    if known.is_empty()
+        || known
+            .iter()
+            .any(|entry| as_str(entry).eq_ignore_ascii_case(value))
+    {
+        return None;
+    }
+    unknown_value(value, util::suggestions(value, known.iter().map(as_str)))
+}
+
+fn check_type_line(catalog: &Catalog, value: &str) -> Option<FilterDiagnostic> {
+    if catalog.types.is_empty() && catalog.supertypes.is_empty() && catalog.subtypes.is_empty() {
+        return None;
+    }
+    let known: Vec<&str> = catalog
+        .types
+        .iter()
+        .map(|value| value.as_str())
+        .chain(catalog.supertypes.iter().map(|value| value.as_str()))
+        .chain(catalog.subtypes.iter().map(String::as_str))
+        .collect();
+    let is_known = |term: &str| known.iter().any(|entry| entry.eq_ignore_ascii_case(term));
+    if is_known(value) {
+        return None;
+    }
+    let unknown: Vec<&str> = value
+        .split_whitespace()
+        .filter(|word| !is_known(word))
+        .collect();
+    if unknown.is_empty() {
+        return None;
+    }
+    let suggestions = unknown
+        .iter()
+        .flat_map(|word| util::suggestions(word, known.iter().cloned()))
+        .collect();
+    unknown_value(&unknown.join(" "), suggestions)
+}
+
+fn check_kind(key: &str, kind: ValueKind, value: &str) -> Option<FilterDiagnostic> {
+    match kind {
+        ValueKind::Text => None,
+        ValueKind::Number => match value.parse::<f64>() {
+            Ok(_) => None,
+            Err(_) => Some(FilterDiagnostic {
+                kind: DiagnosticKind::InvalidValue,
+                key: String::new(),
+                value: Some(value.to_string()),
+                suggestions: Vec::new(),
+            }),
+        },
+        ValueKind::Known(is_known) if is_known(value) => None,
+        ValueKind::Known(_) => unknown_value(value, util::suggestions(value, known_values(key))),
+    }
+}
+
+fn known_values(key: &str) -> Vec<&'static str> {
+    match key {
+        "language" => CardLanguage::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "layout" => CardLayout::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "colors" => CardColor::values().iter().map(|value| value.as_str()).collect(),
+        "colorIdentity" => CardColorIdentity::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "rarity" => CardRarity::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "gameFormat" => GameFormat::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "legality" => CardLegality::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        "contains" => CardResponseField::values()
+            .iter()
+            .map(|value| value.as_str())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,120 @@
+use crate::api::card::filtertypes::{CardSuperType, CardType, GameFormat};
+use crate::api::format::format_api::FormatApi;
+use crate::api::types::type_api::{SubtypeApi, SupertypeApi, TypeApi};
+use crate::MtgClient;
+use failure::Error;
+
+/// Catalog of the card types, subtypes, supertypes and game formats known by the API at runtime
+///
+/// The filter types are compiled into the client and get out of date, the catalog contains
+/// the values the API currently knows about. Values unknown to this version of the client
+/// are kept as `Other`.
+#[derive(Clone, Default, Debug)]
+pub struct Catalog {
+    pub types: Vec<CardType>,
+    pub subtypes: Vec<String>,
+    pub supertypes: Vec<CardSuperType>,
+    pub formats: Vec<GameFormat>,
+}
+
+impl Catalog {
+    /// Creates a catalog from the raw values returned by the API
+    ///
+    /// The values are trimmed, empty values are removed and duplicates (ignoring the case) are dropped.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let catalog = Catalog::from_values(
+    ///     &["Creature".to_string(), "Battle".to_string(), "Stickers".to_string()],
+    ///     &["Goblin".to_string(), "goblin".to_string(), " Elf ".to_string()],
+    ///     &["Legendary".to_string()],
+    ///     &["Pioneer".to_string(), "".to_string()],
+    /// );
+    /// assert_eq!(catalog.types, vec![CardType::Creature, CardType::Battle, CardType::from("Stickers")]);
+    /// assert_eq!(catalog.subtypes, vec!["Goblin", "Elf"]);
+    /// assert_eq!(catalog.formats, vec![GameFormat::Pioneer]);
+    /// assert_eq!(catalog.unknown_values(), vec!["Stickers"]);
+    /// ```
+    pub fn from_values(
+        types: &[String],
+        subtypes: &[String],
+        supertypes: &[String],
+        formats: &[String],
+    ) -> Catalog {
+        Catalog {
+            types: validated(types),
+            subtypes: validated(subtypes),
+            supertypes: validated(supertypes),
+            formats: validated(formats),
+        }
+    }
+
+    /// Fetches the catalog from the types, subtypes, supertypes and formats endpoints
+    pub async fn fetch(client: &MtgClient) -> Result<Catalog, Error> {
+        Catalog::fetch_from(
+            client.types(),
+            client.subtypes(),
+            client.supertypes(),
+            client.formats(),
+        )
+        .await
+    }
+
+    pub(crate) async fn fetch_from(
+        types: &TypeApi,
+        subtypes: &SubtypeApi,
+        supertypes: &SupertypeApi,
+        formats: &FormatApi,
+    ) -> Result<Catalog, Error> {
+        let (types, subtypes, supertypes, formats) = futures::try_join!(
+            types.catalog(),
+            subtypes.catalog(),
+            supertypes.catalog(),
+            formats.catalog(),
+        )?;
+        Ok(Catalog {
+            types,
+            subtypes,
+            supertypes,
+            formats,
+        })
+    }
+
+    /// Returns the values of the catalog unknown to this version of the client
+    pub fn unknown_values(&self) -> Vec<&str> {
+        let types = self
+            .types
+            .iter()
+            .filter(|value| value.is_other())
+            .map(|value| value.as_str());
+        let supertypes = self
+            .supertypes
+            .iter()
+            .filter(|value| value.is_other())
+            .map(|value| value.as_str());
+        let formats = self
+            .formats
+            .iter()
+            .filter(|value| value.is_other())
+            .map(|value| value.as_str());
+        types.chain(supertypes).chain(formats).collect()
+    }
+}
+
+/// Trims the values, drops empty values and duplicates and converts them into the target type
+pub(crate) fn validated<T>(values: &[String]) -> Vec<T>
+where
+    T: for<'a> From<&'a str>,
+{
+    let mut seen: Vec<String> = Vec::new();
+    let mut validated = Vec::new();
+    for value in values.iter().map(|value| value.trim()) {
+        let key = value.to_lowercase();
+        if value.is_empty() || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key);
+        validated.push(T::from(value));
+    }
+    validated
+}
@@ -36,6 +36,22 @@ pub enum MtgApiErrorKind {
     FormatBodyParseError,
     #[fail(display = "Error: {}", cause)]
     ApiError { cause: String },
+    #[fail(display = "Unknown value: {}", value)]
+    UnknownValue { value: String },
+    #[fail(display = "Unknown filter key: {}", key)]
+    UnknownFilterKey { key: String },
+    #[fail(display = "Invalid value for filter {}: {}", key, value)]
+    InvalidFilterValue { key: String, value: String },
+    #[fail(display = "Invalid filter: {}", cause)]
+    InvalidFilter { cause: String },
+    #[fail(display = "Invalid mana cost: {}", cost)]
+    InvalidManaCost { cost: String },
+    #[fail(display = "Invalid type line: {}", type_line)]
+    InvalidTypeLine { type_line: String },
+    #[fail(display = "Set {} has no booster packs", set)]
+    NoBoosterTemplate { set: String },
+    #[fail(display = "Invalid draft: {}", cause)]
+    InvalidDraft { cause: String },
 }
 
 impl Fail for MtgApiError {
@@ -5,6 +5,8 @@ use reqwest::Client;
 
 use std::rc::Weak;
 
+use crate::api::card::filtertypes::GameFormat;
+use crate::api::catalog;
 use crate::api::response::ApiResponse;
 use crate::api::util;
 
@@ -29,4 +31,10 @@ impl FormatApi {
         let formats = util::retrieve_formats_from_body(&body)?;
         Ok(ApiResponse::new(formats, headers))
     }
+
+    /// Returns all formats as a validated catalog, formats unknown to the client are kept as `GameFormat::Other`
+    #[allow(dead_code)]
+    pub async fn catalog(&self) -> Result<Vec<GameFormat>, Error> {
+        Ok(catalog::validated(&self.all().await?.content))
+    }
 }
@@ -0,0 +1,115 @@
+/// Declares a filter type from a table of its known values and their representation in the
+/// filter, optionally followed by aliases which are accepted when parsing
+///
+/// The generated enum keeps unknown values as `Other`, so that values added to the API later
+/// survive a round trip.
+/// It (de)serializes as its representation.
+macro_rules! filter_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($variant:ident => $value:literal $(| $alias:literal)*,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(dead_code)]
+        #[derive(Clone, Eq, PartialEq, Hash, Debug)]
+        pub enum $name {
+            $($variant,)+
+            /// A value unknown to this version of the client
+            Other(String),
+        }
+
+        impl $name {
+            /// Returns all known values
+            #[allow(dead_code)]
+            pub fn values() -> &'static [$name] {
+                &[$($name::$variant,)+]
+            }
+
+            /// Creates the representation expected by the filter
+            #[allow(dead_code)]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Other(value) => value,
+                }
+            }
+
+            /// Returns true if the value is not known to this version of the client
+            #[allow(dead_code)]
+            pub fn is_other(&self) -> bool {
+                matches!(self, $name::Other(_))
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::failure::Error;
+
+            /// Parses a known value or one of its aliases, unknown values are rejected
+            fn from_str(value: &str) -> Result<$name, ::failure::Error> {
+                let trimmed = value.trim();
+                $(
+                    if trimmed.eq_ignore_ascii_case($value)
+                        $(|| trimmed.eq_ignore_ascii_case($alias))*
+                    {
+                        return Ok($name::$variant);
+                    }
+                )+
+                Err($crate::api::error::MtgApiErrorKind::UnknownValue {
+                    value: value.to_string(),
+                }
+                .into())
+            }
+        }
+
+        impl From<&str> for $name {
+            /// Converts the value, unknown values are kept as `Other`
+            fn from(value: &str) -> $name {
+                value
+                    .parse()
+                    .unwrap_or_else(|_| $name::Other(value.trim().to_string()))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> $name {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> String {
+                match value {
+                    $name::Other(value) => value,
+                    value => value.as_str().to_string(),
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                String::deserialize(deserializer).map($name::from)
+            }
+        }
+
+        impl Default for $name {
+            /// An empty value, e.g. for fields missing in the response
+            fn default() -> $name {
+                $name::Other(String::new())
+            }
+        }
+    };
+}
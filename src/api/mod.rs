@@ -1,6 +1,11 @@
+#[macro_use]
+mod macros;
+
 pub mod card;
+pub mod catalog;
 pub mod error;
 pub mod format;
+pub mod query;
 pub mod response;
 pub mod set;
 pub mod types;
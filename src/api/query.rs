@@ -0,0 +1,213 @@
+use crate::api::error::MtgApiErrorKind;
+use failure::Error;
+use itertools::Itertools;
+
+pub(crate) const SEP_AND: &str = ",";
+pub(crate) const SEP_OR: &str = "|";
+
+/// How the values of a single filter parameter are combined
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum FilterCombinator {
+    /// Every value has to match (values separated by `,`)
+    And,
+    /// One of the values has to match (values separated by `|`)
+    Or,
+}
+
+/// A single `key=value` parameter of a filter query string
+#[derive(Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FilterParam {
+    pub key: String,
+    pub values: Vec<String>,
+    pub combinator: FilterCombinator,
+}
+
+impl FilterParam {
+    /// Parses a single `key=value` parameter
+    ///
+    /// `,` binds tighter than `|`: if the value contains a `|`, it is split into alternatives
+    /// on `|` only and every alternative keeps its `,` separated values, i.e. `a,b|c` means
+    /// (a and b) or c.
+    ///
+    /// ```
+    /// # use mtgapi_client::api::query::*;
+    /// let param = FilterParam::parse("colors=Red|Blue");
+    /// assert_eq!(param.key, "colors");
+    /// assert_eq!(param.values, vec!["Red", "Blue"]);
+    /// assert_eq!(param.combinator, FilterCombinator::Or);
+    ///
+    /// let param = FilterParam::parse("colors=Red,Blue|Green");
+    /// assert_eq!(param.values, vec!["Red,Blue", "Green"]);
+    /// assert_eq!(param.combinator, FilterCombinator::Or);
+    /// ```
+    pub fn parse(param: &str) -> FilterParam {
+        let (key, value) = match param.find('=') {
+            Some(index) => (&param[..index], &param[index + 1..]),
+            None => (param, ""),
+        };
+        let (values, combinator) = if value.contains(SEP_OR) {
+            (
+                value.split(SEP_OR).collect::<Vec<_>>(),
+                FilterCombinator::Or,
+            )
+        } else {
+            (
+                value.split(SEP_AND).collect::<Vec<_>>(),
+                FilterCombinator::And,
+            )
+        };
+        FilterParam {
+            key: key.trim().to_string(),
+            values: values
+                .into_iter()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect(),
+            combinator,
+        }
+    }
+
+    /// Joins the values with the separator of the combinator
+    pub fn value(&self) -> String {
+        match self.combinator {
+            FilterCombinator::And => self.values.join(SEP_AND),
+            FilterCombinator::Or => self.values.join(SEP_OR),
+        }
+    }
+
+    /// Returns every single value, the alternatives of an `Or` parameter split into their `,`
+    /// separated values
+    ///
+    /// ```
+    /// # use mtgapi_client::api::query::*;
+    /// let param = FilterParam::parse("colors=Red,Blue|Green");
+    /// assert_eq!(param.terms(), vec!["Red", "Blue", "Green"]);
+    /// ```
+    pub fn terms(&self) -> Vec<&str> {
+        self.values.iter().flat_map(|value| split_and(value)).collect()
+    }
+
+    /// Checks the values with the predicate, combined according to the combinator
+    ///
+    /// Every alternative of an `Or` parameter matches if all of its `,` separated values match.
+    ///
+    /// ```
+    /// # use mtgapi_client::api::query::*;
+    /// let param = FilterParam::parse("colors=Red,Blue");
+    /// assert!(param.matches(|color| color == "Red" || color == "Blue"));
+    /// assert!(!param.matches(|color| color == "Red"));
+    ///
+    /// let param = FilterParam::parse("colors=Red,Blue|Green");
+    /// assert!(param.matches(|color| color == "Green"));
+    /// assert!(param.matches(|color| color == "Red" || color == "Blue"));
+    /// assert!(!param.matches(|color| color == "Red"));
+    /// ```
+    pub fn matches<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&str) -> bool,
+    {
+        let mut values = self.values.iter().map(String::as_str);
+        match self.combinator {
+            FilterCombinator::And => values.all(predicate),
+            FilterCombinator::Or => values.any(|alternative| split_and(alternative).all(&predicate)),
+        }
+    }
+}
+
+/// Splits the `,` separated values of an alternative
+fn split_and(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(SEP_AND)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// The kind of values a filter key accepts
+#[derive(Copy, Clone)]
+pub(crate) enum ValueKind {
+    Text,
+    Number,
+    Known(fn(&str) -> bool),
+}
+
+/// Splits a query string into its parameters
+pub(crate) fn parse_params(query: &str) -> Vec<FilterParam> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(FilterParam::parse)
+        .collect()
+}
+
+/// Joins parameters back into a query string
+pub(crate) fn join_params(params: &[FilterParam]) -> String {
+    params
+        .iter()
+        .map(|param| [param.key.clone(), param.value()].join("="))
+        .join("&")
+}
+
+/// Parses the query string and validates every parameter with the `kind_of` lookup
+pub(crate) fn parse_validated<F>(query: &str, kind_of: F) -> Result<Vec<FilterParam>, Error>
+where
+    F: Fn(&str) -> Option<ValueKind>,
+{
+    let params = parse_params(query);
+    validate_params(&params, kind_of)?;
+    Ok(params)
+}
+
+/// Validates every parameter with the `kind_of` lookup
+pub(crate) fn validate_params<F>(params: &[FilterParam], kind_of: F) -> Result<(), Error>
+where
+    F: Fn(&str) -> Option<ValueKind>,
+{
+    for param in params {
+        let kind = kind_of(&param.key).ok_or_else(|| MtgApiErrorKind::UnknownFilterKey {
+            key: param.key.clone(),
+        })?;
+        if param.values.is_empty() {
+            return Err(MtgApiErrorKind::InvalidFilterValue {
+                key: param.key.clone(),
+                value: String::new(),
+            }
+            .into());
+        }
+        for value in param.terms() {
+            let valid = match kind {
+                ValueKind::Text => true,
+                ValueKind::Number => value.parse::<f64>().is_ok(),
+                ValueKind::Known(is_known) => is_known(value),
+            };
+            if !valid {
+                return Err(MtgApiErrorKind::InvalidFilterValue {
+                    key: param.key.clone(),
+                    value: value.to_string(),
+                }
+                .into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the length of the value once it is percent-encoded in the query string of a url
+///
+/// Spaces, quotes, `#`, `<`, `>` as well as control and non-ASCII characters are encoded
+/// per byte, the same way the url of a request is encoded.
+pub(crate) fn encoded_len(value: &str) -> usize {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b' ' | b'"' | b'#' | b'<' | b'>' | b'\'' => 3,
+            byte if !(0x21..=0x7e).contains(&byte) => 3,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Checks whether the haystack contains the needle, ignoring the case
+pub(crate) fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    haystack.to_lowercase().contains(&needle.to_lowercase())
+}
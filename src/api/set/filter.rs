@@ -1,9 +1,26 @@
-use crate::api::set::filtertypes::SetBlock;
+use crate::api::query;
+use crate::api::query::{FilterParam, ValueKind};
+use crate::api::set::filtertypes::{SetBlock, SetBorder, SetType};
+use crate::model::ids::DATE_FORMAT;
+use chrono::NaiveDate;
+use failure::Error;
 use itertools::Itertools;
+use std::convert::TryFrom;
 use std::fmt::Display;
+use std::str::FromStr;
 
 const SEP_OR: &str = "|";
 
+/// The filter keys evaluated client-side, all other keys are sent to the sets endpoint
+const CLIENT_KEYS: &[&str] = &[
+    "releasedAfter",
+    "releasedBefore",
+    "type",
+    "border",
+    "onlineOnly",
+    "code",
+];
+
 /// Builder for filtered set requests
 #[derive(Clone, Debug)]
 pub struct SetFilterBuilder {
@@ -119,6 +136,151 @@ impl SetFilterBuilder {
         self
     }
 
+    /// Every set released on or after the specified date will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.released_after(NaiveDate::from_ymd_opt(2018, 1, 1).unwrap())
+    ///     .build();
+    /// assert!(filter == SetFilter("releasedAfter=2018-01-01".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn released_after(mut self, date: NaiveDate) -> SetFilterBuilder {
+        self.add_filter("releasedAfter", &date.format(DATE_FORMAT).to_string());
+        self
+    }
+
+    /// Every set released on or before the specified date will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.released_before(NaiveDate::from_ymd_opt(2018, 12, 31).unwrap())
+    ///     .build();
+    /// assert!(filter == SetFilter("releasedBefore=2018-12-31".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn released_before(mut self, date: NaiveDate) -> SetFilterBuilder {
+        self.add_filter("releasedBefore", &date.format(DATE_FORMAT).to_string());
+        self
+    }
+
+    /// Every set released between the specified dates (inclusive) will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use chrono::NaiveDate;
+    /// let builder = SetFilter::builder();
+    /// let from = NaiveDate::from_ymd_opt(2018, 1, 1).unwrap();
+    /// let to = NaiveDate::from_ymd_opt(2018, 12, 31).unwrap();
+    /// let filter = builder.released_between(from, to)
+    ///     .build();
+    /// assert!(filter == SetFilter("releasedAfter=2018-01-01&releasedBefore=2018-12-31".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn released_between(self, from: NaiveDate, to: NaiveDate) -> SetFilterBuilder {
+        self.released_after(from).released_before(to)
+    }
+
+    /// Every set of the specified type will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.set_type(SetType::Expansion)
+    ///     .build();
+    /// assert!(filter == SetFilter("type=expansion".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn set_type(mut self, set_type: SetType) -> SetFilterBuilder {
+        self.add_filter("type", set_type.as_str());
+        self
+    }
+
+    /// Every set of one of the specified types will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.set_types(&vec![SetType::Core, SetType::Expansion])
+    ///     .build();
+    /// assert!(filter == SetFilter("type=core|expansion".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn set_types(mut self, set_types: &[SetType]) -> SetFilterBuilder {
+        let values = set_types.iter().map(|value| value.as_str()).join(SEP_OR);
+        self.add_filter("type", &values);
+        self
+    }
+
+    /// Every set with the specified border will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.border(SetBorder::White)
+    ///     .build();
+    /// assert!(filter == SetFilter("border=white".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn border(mut self, border: SetBorder) -> SetFilterBuilder {
+        self.add_filter("border", border.as_str());
+        self
+    }
+
+    /// Every set that is (or is not) only available online will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.online_only(false)
+    ///     .build();
+    /// assert!(filter == SetFilter("onlineOnly=false".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn online_only(mut self, online_only: bool) -> SetFilterBuilder {
+        self.add_filter("onlineOnly", &online_only.to_string());
+        self
+    }
+
+    /// Every set with one of the specified set codes will match the filter
+    ///
+    /// This filter is evaluated client-side
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let builder = SetFilter::builder();
+    /// let filter = builder.codes(&vec!["DOM", "M19"])
+    ///     .build();
+    /// assert!(filter == SetFilter("code=DOM|M19".to_string()))
+    /// ```
+    #[allow(dead_code)]
+    pub fn codes<T>(mut self, codes: &[T]) -> SetFilterBuilder
+    where
+        T: Display,
+    {
+        let values = codes.iter().join(SEP_OR);
+        self.add_filter("code", &values);
+        self
+    }
+
     fn add_filter<T>(&mut self, key: T, values: T)
     where
         T: Display,
@@ -131,7 +293,23 @@ impl SetFilterBuilder {
 }
 
 /// Wrapper around the filter string to be used for filtered set api requests
-#[derive(PartialEq, Eq, Clone, Debug)]
+///
+/// The filter is (de)serialized as its query string. When deserializing, the values of known keys
+/// are validated, while unknown keys and the query string itself are kept as they are.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// let filter = SetFilter::builder()
+///     .block(SetBlock::Ixalan)
+///     .custom("digital", "false")
+///     .build();
+/// let json = serde_json::to_string(&filter).unwrap();
+/// assert_eq!(json, "\"block=Ixalan&digital=false\"");
+/// assert_eq!(serde_json::from_str::<SetFilter>(&json).unwrap(), filter);
+/// assert!(serde_json::from_str::<SetFilter>("\"block=Atlantis\"").is_err());
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct SetFilter(pub String);
 
 impl SetFilter {
@@ -140,4 +318,95 @@ impl SetFilter {
     pub fn builder() -> SetFilterBuilder {
         SetFilterBuilder::new()
     }
+
+    /// Parses an existing query string into a SetFilter
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = SetFilter::parse("name=Dominaria|Core Set 2019").unwrap();
+    /// assert!(filter == SetFilter::builder().names(&["Dominaria", "Core Set 2019"]).build());
+    ///
+    /// assert!(SetFilter::parse("artist=Kev Walker").is_err());
+    /// assert!(SetFilter::parse("type=expansion&onlineOnly=maybe").is_err());
+    /// assert!(SetFilter::parse("block=Atlantis").is_err());
+    /// ```
+    pub fn parse(query: &str) -> Result<SetFilter, Error> {
+        let params = query::parse_validated(query, value_kind)?;
+        Ok(SetFilter(query::join_params(&params)))
+    }
+
+    /// Returns the structured parameters of the filter
+    pub fn params(&self) -> Vec<FilterParam> {
+        query::parse_params(&self.0)
+    }
+
+    /// Creates a SetFilterBuilder from this filter, to extend it with further parameters
+    pub fn into_builder(self) -> SetFilterBuilder {
+        SetFilterBuilder { filter: self.0 }
+    }
+
+    /// Returns the part of the filter which is sent to the sets endpoint
+    ///
+    /// The keys evaluated client-side are stripped, custom keys are kept.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// let filter = SetFilter::builder()
+    ///     .name("Dominaria")
+    ///     .online_only(false)
+    ///     .custom("gameFormat", "Modern")
+    ///     .build();
+    /// assert_eq!(filter.server_query(), "name=Dominaria&gameFormat=Modern");
+    /// ```
+    pub fn server_query(&self) -> String {
+        let params: Vec<FilterParam> = self
+            .params()
+            .into_iter()
+            .filter(|param| !CLIENT_KEYS.contains(&param.key.as_str()))
+            .collect();
+        query::join_params(&params)
+    }
+}
+
+impl FromStr for SetFilter {
+    type Err = Error;
+
+    fn from_str(query: &str) -> Result<SetFilter, Error> {
+        SetFilter::parse(query)
+    }
+}
+
+impl TryFrom<String> for SetFilter {
+    type Error = Error;
+
+    /// Validates the values of known keys, unknown keys (e.g. from `custom`) and the raw
+    /// query string are kept as they are
+    fn try_from(query: String) -> Result<SetFilter, Error> {
+        let params: Vec<FilterParam> = query::parse_params(&query)
+            .into_iter()
+            .filter(|param| value_kind(&param.key).is_some())
+            .collect();
+        query::validate_params(&params, value_kind)?;
+        Ok(SetFilter(query))
+    }
+}
+
+impl From<SetFilter> for String {
+    fn from(filter: SetFilter) -> String {
+        filter.0
+    }
+}
+
+fn value_kind(key: &str) -> Option<ValueKind> {
+    match key {
+        "name" | "code" => Some(ValueKind::Text),
+        "block" => Some(ValueKind::Known(|value| value.parse::<SetBlock>().is_ok())),
+        "releasedAfter" | "releasedBefore" => Some(ValueKind::Known(|value| {
+            NaiveDate::parse_from_str(value, DATE_FORMAT).is_ok()
+        })),
+        "type" => Some(ValueKind::Known(|value| value.parse::<SetType>().is_ok())),
+        "border" => Some(ValueKind::Known(|value| value.parse::<SetBorder>().is_ok())),
+        "onlineOnly" => Some(ValueKind::Known(|value| value.parse::<bool>().is_ok())),
+        _ => None,
+    }
 }
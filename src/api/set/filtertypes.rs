@@ -1,67 +1,77 @@
-/// Available languages for the language filter
-#[allow(dead_code)]
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub enum SetBlock {
-    IceAge,
-    Mirage,
-    Tempest,
-    Urza,
-    Masques,
-    Invasion,
-    Odyssey,
-    Onslaught,
-    Mirrodin,
-    Kamigawa,
-    Ravnica,
-    TimeSpiral,
-    Lorwyn,
-    Shadowmoor,
-    Alara,
-    Zendikar,
-    ScarsOfMirrodin,
-    Innistrad,
-    ReturnToRavnica,
-    Theros,
-    KhansOfTarkir,
-    BattleForZendikar,
-    ShadowsOverInnistrad,
-    Kaladesh,
-    Amonkhet,
-    Ixalan,
+filter_type! {
+    /// Available blocks for the block filter
+    SetBlock {
+        IceAge => "Ice Age",
+        Mirage => "Mirage",
+        Tempest => "Tempest",
+        Urza => "Urza",
+        Masques => "Masques",
+        Invasion => "Invasion",
+        Odyssey => "Odyssey",
+        Onslaught => "Onslaught",
+        Mirrodin => "Mirrodin",
+        Kamigawa => "Kamigawa",
+        Ravnica => "Ravnica",
+        TimeSpiral => "Time Spiral",
+        Lorwyn => "Lorwyn",
+        Shadowmoor => "Shadowmoor",
+        Alara => "Alara",
+        Zendikar => "Zendikar",
+        ScarsOfMirrodin => "Scars of Mirrodin",
+        Innistrad => "Innistrad",
+        ReturnToRavnica => "Return to Ravnica",
+        Theros => "Theros",
+        KhansOfTarkir => "Khans of Tarkir",
+        BattleForZendikar => "Battle for Zendikar",
+        ShadowsOverInnistrad => "Shadows over Innistrad",
+        Kaladesh => "Kaladesh",
+        Amonkhet => "Amonkhet",
+        Ixalan => "Ixalan",
+        GuildsOfRavnica => "Guilds of Ravnica",
+        CoreSet => "Core Set",
+        Commander => "Commander",
+    }
+}
+
+filter_type! {
+    /// Available set types for the set type filter
+    SetType {
+        Core => "core",
+        Expansion => "expansion",
+        Reprint => "reprint",
+        Box => "box",
+        Un => "un",
+        Funny => "funny",
+        FromTheVault => "from the vault",
+        PremiumDeck => "premium deck",
+        DuelDeck => "duel deck",
+        Starter => "starter",
+        Commander => "commander",
+        Planechase => "planechase",
+        Archenemy => "archenemy",
+        Promo => "promo",
+        Vanguard => "vanguard",
+        Masters => "masters",
+        Conspiracy => "conspiracy",
+        Masterpiece => "masterpiece",
+        DraftInnovation => "draft_innovation",
+        Spellbook => "spellbook",
+        Arsenal => "arsenal",
+        TreasureChest => "treasure_chest",
+        Alchemy => "alchemy",
+        Memorabilia => "memorabilia",
+        Token => "token",
+        Minigame => "minigame",
+    }
 }
 
-impl SetBlock {
-    #[allow(dead_code)]
-    /// Creates the representation expected by the language filter
-    pub fn as_str(self) -> &'static str {
-        use self::SetBlock::*;
-        match self {
-            IceAge => "Ice Age",
-            Mirage => "Mirage",
-            Tempest => "Tempest",
-            Urza => "Urza",
-            Masques => "Masques",
-            Invasion => "Invasion",
-            Odyssey => "Odyssey",
-            Onslaught => "Onslaught",
-            Mirrodin => "Mirrodin",
-            Kamigawa => "Kamigawa",
-            Ravnica => "Ravnica",
-            TimeSpiral => "TimeSpiral",
-            Lorwyn => "Lorwyn",
-            Shadowmoor => "Shadowmoor",
-            Alara => "Alara",
-            Zendikar => "Zendikar",
-            ScarsOfMirrodin => "ScarsOfMirrodin",
-            Innistrad => "Innistrad",
-            ReturnToRavnica => "Return to Ravnica",
-            Theros => "Theros",
-            KhansOfTarkir => "Khans of Tarkir",
-            BattleForZendikar => "Battle for Zendikar",
-            ShadowsOverInnistrad => "Shadows over Innistrad",
-            Kaladesh => "Kaladesh",
-            Amonkhet => "Amonkhet",
-            Ixalan => "Ixalan",
-        }
+filter_type! {
+    /// Available borders for the border filter
+    SetBorder {
+        Black => "black",
+        White => "white",
+        Silver => "silver",
+        Gold => "gold",
+        Borderless => "borderless",
     }
 }
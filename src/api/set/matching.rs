@@ -0,0 +1,78 @@
+use crate::api::query::contains_ignore_case;
+use crate::api::set::filter::SetFilter;
+use crate::model::ids::DATE_FORMAT;
+use crate::model::set::SetDetail;
+use chrono::NaiveDate;
+use std::borrow::Borrow;
+
+impl SetFilter {
+    /// Evaluates the filter locally against the specified set
+    ///
+    /// Names and blocks match partially, the remaining parameters have to match exactly.
+    /// Custom keys are left to the sets endpoint and match every set.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::set::SetDetail;
+    /// # use chrono::NaiveDate;
+    /// let set = SetDetail {
+    ///     code: "DOM".into(),
+    ///     name: "Dominaria".to_string(),
+    ///     type_: SetType::Expansion,
+    ///     release_date: NaiveDate::from_ymd_opt(2018, 4, 27).unwrap(),
+    ///     border: SetBorder::Black,
+    ///     ..Default::default()
+    /// };
+    /// let filter = SetFilter::builder()
+    ///     .name("domin")
+    ///     .released_after(NaiveDate::from_ymd_opt(2018, 1, 1).unwrap())
+    ///     .set_types(&[SetType::Core, SetType::Expansion])
+    ///     .online_only(false)
+    ///     .build();
+    /// assert!(filter.matches(&set));
+    ///
+    /// let filter = SetFilter::builder()
+    ///     .released_before(NaiveDate::from_ymd_opt(2017, 12, 31).unwrap())
+    ///     .build();
+    /// assert!(!filter.matches(&set));
+    /// ```
+    pub fn matches(&self, set: &SetDetail) -> bool {
+        self.params().iter().all(|value| match value.key.as_str() {
+            "name" => value.matches(|name| contains_ignore_case(&set.name, name)),
+            "block" => value.matches(|block| match &set.block {
+                Some(set_block) => contains_ignore_case(set_block, block),
+                None => false,
+            }),
+            "releasedAfter" => {
+                value.matches(|date| match NaiveDate::parse_from_str(date, DATE_FORMAT) {
+                    Ok(date) => set.release_date >= date,
+                    Err(_) => false,
+                })
+            }
+            "releasedBefore" => {
+                value.matches(|date| match NaiveDate::parse_from_str(date, DATE_FORMAT) {
+                    Ok(date) => set.release_date <= date,
+                    Err(_) => false,
+                })
+            }
+            "type" => value.matches(|set_type| set.type_.as_str().eq_ignore_ascii_case(set_type)),
+            "border" => value.matches(|border| set.border.as_str().eq_ignore_ascii_case(border)),
+            "onlineOnly" => value.matches(|online_only| match online_only.parse::<bool>() {
+                Ok(online_only) => set.online_only.unwrap_or(false) == online_only,
+                Err(_) => false,
+            }),
+            "code" => value.matches(|code| set.code.eq_ignore_case(code)),
+            _ => true,
+        })
+    }
+
+    /// Evaluates the filter locally against every set and only yields the matching ones
+    pub fn matching<'a, I>(&'a self, sets: I) -> impl Iterator<Item = I::Item> + 'a
+    where
+        I: IntoIterator + 'a,
+        I::Item: Borrow<SetDetail>,
+    {
+        sets.into_iter()
+            .filter(move |set| self.matches(set.borrow()))
+    }
+}
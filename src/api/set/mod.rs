@@ -1,3 +1,4 @@
 pub mod filter;
 pub mod filtertypes;
+mod matching;
 pub mod set_api;
@@ -1,11 +1,17 @@
+use crate::api::card::card_api::CardApi;
+use crate::api::card::filter::CardFilter;
 use crate::api::error::MtgApiErrorKind;
 use crate::api::set::filter::SetFilter;
+use crate::limited::generator::BoosterGenerator;
+use crate::limited::sealed::{PackOptions, PackSource, SealedPool};
 use failure::Error;
 use failure::ResultExt;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use reqwest::Client;
 
 use crate::model::card::CardDetail;
 use crate::model::set::SetDetail;
+use std::collections::HashMap;
 use std::rc::Weak;
 
 use crate::api::response::ApiResponse;
@@ -34,16 +40,31 @@ impl SetApi {
     }
 
     /// Returns all sets matching the supplied filter
+    ///
+    /// The parts of the filter not supported by the API are evaluated client-side
     #[allow(dead_code)]
     pub async fn all_filtered(&self, filter: SetFilter) -> Result<ApiResponse<Vec<SetDetail>>, Error> {
-        let url = SetApi::create_filtered_url(&self.url, filter);
+        let url = SetApi::create_filtered_url(&self.url, &filter);
         let mut response = util::send_response(&url, &self.client).await?;
         let headers = std::mem::take(response.headers_mut());
         let body = response.text().await.context(MtgApiErrorKind::BodyReadError)?;
-        let sets = util::retrieve_sets_from_body(&body)?;
+        let mut sets = util::retrieve_sets_from_body(&body)?;
+        sets.retain(|set| filter.matches(set));
         Ok(ApiResponse::new(sets, headers))
     }
 
+    /// Returns a Request Object to fetch all sets page by page
+    #[allow(dead_code)]
+    pub fn all_paged(&self) -> Box<AllSetsRequest> {
+        AllSetsRequest::new(self.client.clone(), &self.url, 100, SetFilter(String::new()))
+    }
+
+    /// Returns a Request Object to fetch all sets matching the supplied filter page by page
+    #[allow(dead_code)]
+    pub fn all_filtered_paged(&self, filter: SetFilter) -> Box<AllSetsRequest> {
+        AllSetsRequest::new(self.client.clone(), &self.url, 100, filter)
+    }
+
     /// Returns the specified set by the set code
     pub async fn find<'a, T>(&self, code: T) -> Result<ApiResponse<SetDetail>, Error>
     where
@@ -70,12 +91,271 @@ impl SetApi {
         Ok(ApiResponse::new(cards, headers))
     }
 
-    fn create_filtered_url(api_url: &str, filter: SetFilter) -> String {
+    /// Returns all cards of the specified set in collector number order
+    ///
+    /// All pages of the set's cards are fetched, cards without a collector number come last.
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn cards<'a, T>(&self, code: T) -> Result<ApiResponse<Vec<CardDetail>>, Error>
+    where
+        T: Into<&'a str>,
+    {
+        let filter = CardFilter::builder().set(code.into()).build();
+        let mut request = CardApi::new(self.client.clone(), self.url.clone()).all_filtered(filter);
+        let mut cards = Vec::new();
+        let mut response = loop {
+            let mut response = request.next_page().await?;
+            if response.content.is_empty() {
+                break response;
+            }
+            cards.append(&mut response.content);
+        };
+        cards.sort_by_key(|card| (card.number.is_none(), card.number.clone()));
+        response.count = Some(cards.len() as u32);
+        response.total_count = Some(cards.len() as u32);
+        response.content = cards;
+        Ok(response)
+    }
+
+    /// Returns a generator opening booster packs of the specified set locally
+    ///
+    /// The set and all its cards are fetched once, the packs are opened without further requests.
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API, does not manage to read the response
+    /// or the set has no booster packs, it will return an error.
+    #[allow(dead_code)]
+    pub async fn booster_generator<'a, T>(
+        &self,
+        code: T,
+        seed: u64,
+    ) -> Result<BoosterGenerator, Error>
+    where
+        T: Into<&'a str>,
+    {
+        let code = code.into();
+        let set = self.find(code).await?.content;
+        let cards = self.cards(code).await?.content;
+        BoosterGenerator::for_set(&set, cards, seed)
+    }
+
+    /// Opens a booster box of the specified set, e.g. 36 packs
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn booster_box(&self, code: &str, packs: usize) -> Result<SealedPool, Error> {
+        self.booster_box_with(code, packs, PackOptions::default()).await
+    }
+
+    /// Opens a booster box of the specified set with the options
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn booster_box_with(
+        &self,
+        code: &str,
+        packs: usize,
+        options: PackOptions,
+    ) -> Result<SealedPool, Error> {
+        self.sealed_pool_with(&vec![code; packs], options).await
+    }
+
+    /// Opens one pack of each specified set, e.g. six packs for a sealed pool
+    ///
+    /// The packs are fetched concurrently and returned in the order of the set codes.
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use mtgapi_client::prelude::*;
+    /// # async fn open_pool() -> Result<(), Box<dyn Error>> {
+    /// let sdk = MtgClient::new(60);
+    /// let pool = sdk.sets().sealed_pool(&["KTK", "KTK", "KTK", "KTK", "FRF", "FRF"]).await?;
+    /// for (color, cards) in pool.by_color() {
+    ///     println!("{:?}: {}", color, cards.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    #[allow(dead_code)]
+    pub async fn sealed_pool(&self, codes: &[&str]) -> Result<SealedPool, Error> {
+        self.sealed_pool_with(codes, PackOptions::default()).await
+    }
+
+    /// Opens one pack of each specified set with the options
+    ///
+    /// Packs opened locally fetch every set and its cards once and open all its packs
+    /// from a generator seeded with the seed of the options.
+    ///
+    /// # Errors
+    ///
+    /// If this function can't connect to the API, does not manage to read the response
+    /// or a set has no booster packs, it will return an error.
+    #[allow(dead_code)]
+    pub async fn sealed_pool_with(
+        &self,
+        codes: &[&str],
+        options: PackOptions,
+    ) -> Result<SealedPool, Error> {
+        let concurrency = options.concurrency.max(1);
+        let packs = match options.source {
+            PackSource::Api => {
+                stream::iter(codes.iter().map(|code| self.booster(*code)))
+                    .buffered(concurrency)
+                    .map_ok(|response| response.content)
+                    .try_collect()
+                    .await?
+            }
+            PackSource::Local { seed } => {
+                let mut distinct: Vec<&str> = codes.to_vec();
+                distinct.sort_unstable();
+                distinct.dedup();
+                let mut generators: HashMap<&str, BoosterGenerator> =
+                    stream::iter(distinct.into_iter().map(|code| async move {
+                        Ok::<_, Error>((code, self.booster_generator(code, seed).await?))
+                    }))
+                    .buffer_unordered(concurrency)
+                    .try_collect()
+                    .await?;
+                codes
+                    .iter()
+                    .map(|code| generators.get_mut(code).unwrap().open())
+                    .collect()
+            }
+        };
+        Ok(SealedPool::new(packs))
+    }
+
+    fn create_filtered_url(api_url: &str, filter: &SetFilter) -> String {
         let url = [api_url, "/sets"].join("");
-        if filter.0.is_empty() {
+        let query = filter.server_query();
+        if query.is_empty() {
             url
         } else {
-            [url, filter.0].join("?")
+            [url, query].join("?")
+        }
+    }
+}
+
+/// Request Object to be used to fetch sets page by page
+///
+/// The sets matching the filter are fetched with the first call and then returned page by page,
+/// because parts of the filter are evaluated client-side.
+#[allow(dead_code)]
+pub struct AllSetsRequest {
+    page: u32,
+    client: Weak<Client>,
+    url: String,
+    filter: SetFilter,
+    page_size: u32,
+    sets: Option<Vec<SetDetail>>,
+    ratelimit_limit: Option<u32>,
+    ratelimit_remaining: Option<u32>,
+}
+
+impl AllSetsRequest {
+    fn new(
+        client: Weak<Client>,
+        api_url: &str,
+        page_size: u32,
+        filter: SetFilter,
+    ) -> Box<AllSetsRequest> {
+        Box::new(AllSetsRequest {
+            page: 1,
+            client,
+            url: api_url.to_string(),
+            filter,
+            page_size,
+            sets: None,
+            ratelimit_limit: None,
+            ratelimit_remaining: None,
+        })
+    }
+
+    /// Executes the call to the API.
+    /// Repeated calls to this method will return the different pages of the sets
+    ///
+    /// ```no_run
+    /// # use std::error::Error;
+    /// # use mtgapi_client::prelude::*;
+    /// # async fn get_some_sets() -> Result<(), Box<dyn Error>> {
+    /// let sdk = MtgClient::new(60);
+    /// let mut get_sets_request = sdk.sets().all_filtered_paged(
+    ///     SetFilter::builder().set_type(SetType::Expansion).build()
+    /// );
+    /// get_sets_request.set_page_size(10);
+    /// let mut sets = Vec::new();
+    /// loop {
+    ///     let response = get_sets_request.next_page().await?;
+    ///     if response.content.is_empty() {break}
+    ///     sets.extend(response.content);
+    /// }
+    /// #
+    /// # Ok(())
+    /// # }
+    /// #
+    /// ```
+    /// # Errors
+    ///
+    /// If this function can't connect to the API or does not manage
+    /// to read the response, it will return an error.
+    ///
+    #[allow(dead_code)]
+    pub async fn next_page(&mut self) -> Result<ApiResponse<Vec<SetDetail>>, Error> {
+        if self.sets.is_none() {
+            let url = SetApi::create_filtered_url(&self.url, &self.filter);
+            let mut response = util::send_response(&url, &self.client).await?;
+            let headers = std::mem::take(response.headers_mut());
+            let body = response.text().await.context(MtgApiErrorKind::BodyReadError)?;
+            let mut sets = util::retrieve_sets_from_body(&body)?;
+            sets.retain(|set| self.filter.matches(set));
+            let response = ApiResponse::new((), headers);
+            self.ratelimit_limit = response.ratelimit_limit;
+            self.ratelimit_remaining = response.ratelimit_remaining;
+            self.sets = Some(sets);
         }
+        let sets = self.sets.as_ref().unwrap();
+        let start = self.page.saturating_sub(1) as usize * self.page_size as usize;
+        let content: Vec<SetDetail> = sets
+            .iter()
+            .skip(start)
+            .take(self.page_size as usize)
+            .cloned()
+            .collect();
+        self.page += 1;
+        Ok(ApiResponse {
+            page_size: Some(self.page_size),
+            count: Some(content.len() as u32),
+            total_count: Some(sets.len() as u32),
+            ratelimit_limit: self.ratelimit_limit,
+            ratelimit_remaining: self.ratelimit_remaining,
+            content,
+        })
+    }
+
+    /// Sets the page for the following API calls
+    #[allow(dead_code)]
+    pub fn set_page(&mut self, page: u32) {
+        self.page = page;
+    }
+
+    /// Sets the page size for the following API calls
+    #[allow(dead_code)]
+    pub fn set_page_size(&mut self, size: u32) {
+        self.page_size = size;
     }
 }
@@ -5,6 +5,8 @@ use reqwest::Client;
 
 use std::rc::Weak;
 
+use crate::api::card::filtertypes::{CardSuperType, CardType};
+use crate::api::catalog;
 use crate::api::response::ApiResponse;
 use crate::api::util;
 
@@ -41,6 +43,12 @@ impl TypeApi {
         let types = util::retrieve_types_from_body(&body)?;
         Ok(ApiResponse::new(types, headers))
     }
+
+    /// Returns all types as a validated catalog, types unknown to the client are kept as `CardType::Other`
+    #[allow(dead_code)]
+    pub async fn catalog(&self) -> Result<Vec<CardType>, Error> {
+        Ok(catalog::validated(&self.all().await?.content))
+    }
 }
 
 impl SubtypeApi {
@@ -58,6 +66,12 @@ impl SubtypeApi {
         let subtypes = util::retrieve_subtypes_from_body(&body)?;
         Ok(ApiResponse::new(subtypes, headers))
     }
+
+    /// Returns all subtypes as a validated catalog
+    #[allow(dead_code)]
+    pub async fn catalog(&self) -> Result<Vec<String>, Error> {
+        Ok(catalog::validated(&self.all().await?.content))
+    }
 }
 
 impl SupertypeApi {
@@ -75,4 +89,10 @@ impl SupertypeApi {
         let supertypes = util::retrieve_supertypes_from_body(&body)?;
         Ok(ApiResponse::new(supertypes, headers))
     }
+
+    /// Returns all supertypes as a validated catalog, supertypes unknown to the client are kept as `CardSuperType::Other`
+    #[allow(dead_code)]
+    pub async fn catalog(&self) -> Result<Vec<CardSuperType>, Error> {
+        Ok(catalog::validated(&self.all().await?.content))
+    }
 }
@@ -120,4 +120,41 @@ pub(crate) fn retrieve_supertypes_from_body(body: &str) -> Result<Vec<String>, E
             None => Err(MtgApiErrorKind::ApiError { cause: error }.into()),
         },
     }
-}
\ No newline at end of file
+}
+
+/// Levenshtein distance between two strings, ignoring the case
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+/// Returns up to three candidates close to the value, the closest first
+pub(crate) fn suggestions<'a, I>(value: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let max_distance = std::cmp::max(1, value.chars().count() / 3);
+    let mut close: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(value, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    close.sort_by_key(|(distance, _)| *distance);
+    close.dedup_by(|a, b| a.1.eq_ignore_ascii_case(b.1));
+    close
+        .into_iter()
+        .take(3)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
@@ -7,28 +7,37 @@ extern crate log;
 
 extern crate chrono;
 extern crate failure;
+extern crate futures;
 extern crate itertools;
+extern crate rand;
+extern crate rand_chacha;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
 
 pub use crate::api::card::card_api::CardApi as cards;
 use crate::api::card::card_api::CardApi;
+use crate::api::catalog::Catalog;
 use crate::api::format::format_api::FormatApi;
 use crate::api::set::set_api::SetApi;
 use crate::api::types::type_api::SubtypeApi;
 use crate::api::types::type_api::SupertypeApi;
 use crate::api::types::type_api::TypeApi;
+use failure::Error;
 use reqwest::Client;
 use std::time::Duration;
 use std::rc::Rc;
 
 pub mod api;
+pub mod limited;
 pub mod model;
 
 pub mod prelude {
     pub use crate::api::card::filter::*;
     pub use crate::api::card::filtertypes::*;
+    pub use crate::api::card::validation::*;
+    pub use crate::api::catalog::Catalog;
+    pub use crate::api::query::*;
     pub use crate::api::set::filter::*;
     pub use crate::api::set::filtertypes::*;
     pub use crate::MtgClient;
@@ -110,4 +119,9 @@ impl MtgClient {
     pub fn formats(&self) -> &FormatApi {
         &self.formats
     }
+
+    /// Fetches the catalog of types, subtypes, supertypes and formats currently known by the API
+    pub async fn catalog(&self) -> Result<Catalog, Error> {
+        Catalog::fetch(self).await
+    }
 }
@@ -0,0 +1,300 @@
+use crate::api::card::filtertypes::{CardColor, CardRarity};
+use crate::api::error::MtgApiErrorKind;
+use crate::model::card::CardDetail;
+use failure::Error;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The number of picks after which a `ColorPicker` commits to its colors
+pub const DEFAULT_COMMIT_AFTER: usize = 5;
+
+/// The number of rounds of a draft, i.e. the packs opened by every seat
+pub const ROUNDS: usize = 3;
+
+/// Chooses a card from a pack during a draft
+pub trait Picker {
+    /// Returns the index of the picked card in the pack, which is never empty
+    fn pick(&mut self, pack: &[CardDetail], picks: &[CardDetail], rng: &mut dyn RngCore) -> usize;
+}
+
+/// Picks a random card
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RandomPicker;
+
+impl Picker for RandomPicker {
+    fn pick(&mut self, pack: &[CardDetail], _picks: &[CardDetail], rng: &mut dyn RngCore) -> usize {
+        rng.gen_range(0..pack.len())
+    }
+}
+
+/// Picks the card of the highest rarity, ties are broken randomly
+#[derive(Copy, Clone, Default, Debug)]
+pub struct RarityPicker;
+
+impl Picker for RarityPicker {
+    fn pick(&mut self, pack: &[CardDetail], _picks: &[CardDetail], rng: &mut dyn RngCore) -> usize {
+        best_of(pack, |card| rarity_rank(&card.rarity), rng)
+    }
+}
+
+/// Picks by rarity until it commits to the two colors it picked most,
+/// afterwards cards of those colors and colorless cards are preferred
+#[derive(Copy, Clone, Debug)]
+pub struct ColorPicker {
+    pub commit_after: usize,
+}
+
+impl Default for ColorPicker {
+    fn default() -> ColorPicker {
+        ColorPicker {
+            commit_after: DEFAULT_COMMIT_AFTER,
+        }
+    }
+}
+
+impl Picker for ColorPicker {
+    fn pick(&mut self, pack: &[CardDetail], picks: &[CardDetail], rng: &mut dyn RngCore) -> usize {
+        if picks.len() < self.commit_after {
+            return best_of(pack, |card| rarity_rank(&card.rarity), rng);
+        }
+        let colors = main_colors(picks);
+        best_of(
+            pack,
+            |card| {
+                let on_color = card.colors.iter().all(|color| colors.contains(color));
+                on_color as u32 * 10 + rarity_rank(&card.rarity)
+            },
+            rng,
+        )
+    }
+}
+
+/// The serializable state of a draft, which can be stored and resumed later
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DraftState {
+    pub seats: usize,
+    pub seed: u64,
+    /// The current round, starting with 0
+    pub round: usize,
+    /// The number of picks made in the current round
+    pub pick: usize,
+    /// The unopened packs of every round, indexed by round and seat
+    pub unopened: Vec<Vec<Vec<CardDetail>>>,
+    /// The pack in front of every seat
+    pub packs: Vec<Vec<CardDetail>>,
+    /// The cards picked by every seat in the order they were picked
+    pub picks: Vec<Vec<CardDetail>>,
+}
+
+impl DraftState {
+    /// Returns the number of rounds, i.e. the packs opened by every seat
+    pub fn rounds(&self) -> usize {
+        self.unopened.len()
+    }
+
+    /// Returns true if all cards have been picked
+    pub fn is_finished(&self) -> bool {
+        self.packs.iter().all(Vec::is_empty) && self.round + 1 >= self.rounds()
+    }
+
+    /// Returns true if the packs of the current round are passed to the left, i.e. to the next seat
+    pub fn passes_left(&self) -> bool {
+        self.round % 2 == 0
+    }
+}
+
+/// A booster draft of several seats, each picking with its own `Picker`
+///
+/// Every seat opens a pack per round, picks a card and passes the rest of the pack,
+/// alternating between left and right every round.
+/// The draft is deterministic for the same packs, pickers and seed.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::limited::draft::*;
+/// let card = |number: usize| CardDetail {
+///     name: format!("Card {}", number),
+///     rarity: if number % 5 == 0 { CardRarity::Rare } else { CardRarity::Common },
+///     ..Default::default()
+/// };
+/// // two seats, three rounds, packs of five cards
+/// let packs: Vec<Vec<CardDetail>> = (0..6)
+///     .map(|pack| (0..5).map(|index| card(pack * 5 + index)).collect())
+///     .collect();
+/// let pickers: Vec<Box<dyn Picker>> =
+///     vec![Box::new(RarityPicker), Box::new(ColorPicker::default())];
+///
+/// let mut draft = Draft::new(packs.clone(), pickers, 7).unwrap();
+/// draft.run();
+/// let picks = &draft.state().picks;
+/// assert_eq!(picks[0].len(), 15);
+/// assert_eq!(picks[1].len(), 15);
+/// assert_eq!(picks[0][0].rarity, CardRarity::Rare);
+///
+/// let state = serde_json::to_string(draft.state()).unwrap();
+/// let mut state: DraftState = serde_json::from_str(&state).unwrap();
+/// assert!(state.is_finished());
+///
+/// state.packs.pop();
+/// assert!(Draft::resume(state, vec![Box::new(RandomPicker), Box::new(RandomPicker)]).is_err());
+/// assert!(Draft::new(packs[..4].to_vec(), vec![Box::new(RandomPicker)], 7).is_err());
+/// ```
+pub struct Draft {
+    state: DraftState,
+    pickers: Vec<Box<dyn Picker>>,
+}
+
+impl Draft {
+    /// Creates a draft with a seat for every picker from the packs of all rounds
+    ///
+    /// The packs are handed out in order, the first round's packs first,
+    /// e.g. the packs of `SetApi::booster_box` opened for eight seats and three rounds.
+    ///
+    /// # Errors
+    ///
+    /// If there are no pickers or there aren't exactly `ROUNDS` packs for every seat,
+    /// it will return an error.
+    pub fn new(
+        packs: Vec<Vec<CardDetail>>,
+        pickers: Vec<Box<dyn Picker>>,
+        seed: u64,
+    ) -> Result<Draft, Error> {
+        let seats = pickers.len();
+        if seats == 0 || packs.len() != seats * ROUNDS {
+            return Err(MtgApiErrorKind::InvalidDraft {
+                cause: format!(
+                    "{} packs instead of {} for {} seats",
+                    packs.len(),
+                    seats * ROUNDS,
+                    seats
+                ),
+            }
+            .into());
+        }
+        let unopened: Vec<Vec<Vec<CardDetail>>> =
+            packs.chunks(seats).map(|round| round.to_vec()).collect();
+        let state = DraftState {
+            seats,
+            seed,
+            round: 0,
+            pick: 0,
+            packs: unopened[0].clone(),
+            unopened,
+            picks: vec![Vec::new(); seats],
+        };
+        Ok(Draft { state, pickers })
+    }
+
+    /// Resumes a stored draft with a picker for every seat
+    ///
+    /// # Errors
+    ///
+    /// If the number of pickers doesn't match the seats of the draft or the state is inconsistent,
+    /// e.g. its packs don't match its seats, it will return an error.
+    pub fn resume(state: DraftState, pickers: Vec<Box<dyn Picker>>) -> Result<Draft, Error> {
+        let cause = if pickers.len() != state.seats {
+            format!("{} pickers for {} seats", pickers.len(), state.seats)
+        } else if state.packs.len() != state.seats || state.picks.len() != state.seats {
+            format!(
+                "{} packs and {} picks for {} seats",
+                state.packs.len(),
+                state.picks.len(),
+                state.seats
+            )
+        } else if state.round >= state.rounds() {
+            format!("round {} of {} rounds", state.round, state.rounds())
+        } else if state
+            .unopened
+            .iter()
+            .any(|round| round.len() != state.seats)
+        {
+            format!(
+                "unopened rounds without a pack for each of {} seats",
+                state.seats
+            )
+        } else {
+            return Ok(Draft { state, pickers });
+        };
+        Err(MtgApiErrorKind::InvalidDraft { cause }.into())
+    }
+
+    /// Returns the current state of the draft
+    pub fn state(&self) -> &DraftState {
+        &self.state
+    }
+
+    /// Lets every seat pick a card and passes the packs, the next round is opened when needed
+    ///
+    /// Returns false if the draft is finished.
+    pub fn step(&mut self) -> bool {
+        if self.state.is_finished() {
+            return false;
+        }
+        if self.state.packs.iter().all(Vec::is_empty) {
+            self.state.round += 1;
+            self.state.pick = 0;
+            self.state.packs = self.state.unopened[self.state.round].clone();
+        }
+        let mut rng = ChaCha8Rng::seed_from_u64(self.state.seed);
+        rng.set_stream(((self.state.round as u64) << 32) | self.state.pick as u64);
+        for seat in 0..self.state.seats {
+            let pack = &mut self.state.packs[seat];
+            if pack.is_empty() {
+                continue;
+            }
+            let picks = &mut self.state.picks[seat];
+            let index = self.pickers[seat].pick(pack, picks, &mut rng);
+            picks.push(pack.remove(index.min(pack.len() - 1)));
+        }
+        if self.state.passes_left() {
+            self.state.packs.rotate_right(1);
+        } else {
+            self.state.packs.rotate_left(1);
+        }
+        self.state.pick += 1;
+        true
+    }
+
+    /// Runs the draft to the end and returns the picks of every seat
+    pub fn run(&mut self) -> &[Vec<CardDetail>] {
+        while self.step() {}
+        &self.state.picks
+    }
+}
+
+/// Returns the index of a card with the highest score, ties are broken randomly
+fn best_of<F>(pack: &[CardDetail], score: F, rng: &mut dyn RngCore) -> usize
+where
+    F: Fn(&CardDetail) -> u32,
+{
+    let scores: Vec<u32> = pack.iter().map(score).collect();
+    let best = scores.iter().max().cloned().unwrap_or_default();
+    let candidates: Vec<usize> = (0..pack.len())
+        .filter(|index| scores[*index] == best)
+        .collect();
+    candidates[rng.gen_range(0..candidates.len())]
+}
+
+fn rarity_rank(rarity: &CardRarity) -> u32 {
+    match rarity {
+        CardRarity::MythicRare => 4,
+        CardRarity::Rare => 3,
+        CardRarity::Uncommon => 2,
+        CardRarity::Common => 1,
+        _ => 0,
+    }
+}
+
+/// The two colors picked most often
+fn main_colors(picks: &[CardDetail]) -> Vec<CardColor> {
+    let mut counts: Vec<(CardColor, usize)> = Vec::new();
+    for color in picks.iter().flat_map(|card| card.colors.iter()) {
+        match counts.iter_mut().find(|(counted, _)| counted == color) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((color.clone(), 1)),
+        }
+    }
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    counts.into_iter().take(2).map(|(color, _)| color).collect()
+}
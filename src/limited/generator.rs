@@ -0,0 +1,197 @@
+use crate::api::card::filtertypes::{CardLayout, CardRarity};
+use crate::api::error::MtgApiErrorKind;
+use crate::model::booster::{BoosterSlot, BoosterTemplate, SlotAlternative};
+use crate::model::card::CardDetail;
+use crate::model::set::SetDetail;
+use failure::Error;
+use rand::Rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+const TIMESHIFTED: &str = "timeshifted";
+const DOUBLE_FACED: &str = "double faced";
+const FOIL: &str = "foil";
+
+/// Opens booster packs locally from the booster template and the cards of a set
+///
+/// The packs are reproducible: the same template, cards and seed open the same packs.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::booster::BoosterTemplate;
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::model::set::Booster;
+/// # use mtgapi_client::limited::generator::BoosterGenerator;
+/// let card = |name: &str, rarity: CardRarity| CardDetail {
+///     name: name.to_string(),
+///     rarity,
+///     ..Default::default()
+/// };
+/// let cards = vec![
+///     card("Goblin Guide", CardRarity::Rare),
+///     card("Craterhoof Behemoth", CardRarity::MythicRare),
+///     card("Counterspell", CardRarity::Uncommon),
+///     card("Shock", CardRarity::Common),
+///     card("Opt", CardRarity::Common),
+///     card("Duress", CardRarity::Common),
+/// ];
+/// let template = BoosterTemplate::from(vec![
+///     Booster::Multiple(vec!["rare".to_string(), "mythic rare".to_string()]),
+///     Booster::Single("uncommon".to_string()),
+///     Booster::Single("common".to_string()),
+///     Booster::Single("common".to_string()),
+///     Booster::Single("marketing".to_string()),
+/// ]);
+///
+/// let mut generator = BoosterGenerator::new(template.clone(), cards.clone(), 42);
+/// let packs = generator.open_many(3);
+/// assert!(packs.iter().all(|pack| pack.len() == 4));
+/// assert!(packs[0][2].name != packs[0][3].name);
+///
+/// let mut again = BoosterGenerator::new(template, cards.clone(), 42);
+/// let names = |pack: &Vec<CardDetail>| pack.iter().map(|card| card.name.clone()).collect::<Vec<_>>();
+/// assert_eq!(names(&again.open()), names(&packs[0]));
+///
+/// let foil = BoosterTemplate::from(vec![Booster::Single("foil".to_string())]);
+/// let mut generator = BoosterGenerator::new(foil, cards, 42);
+/// assert!(generator.open_many(3).iter().all(|pack| pack.len() == 1));
+/// ```
+pub struct BoosterGenerator {
+    template: BoosterTemplate,
+    cards: Vec<CardDetail>,
+    rng: ChaCha8Rng,
+}
+
+impl BoosterGenerator {
+    /// Creates a generator for the template and the card pool with a seed for the packs
+    pub fn new(template: BoosterTemplate, cards: Vec<CardDetail>, seed: u64) -> BoosterGenerator {
+        let cards = cards.into_iter().filter(is_front_face).collect();
+        BoosterGenerator {
+            template,
+            cards,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates a generator for the set and its cards
+    ///
+    /// # Errors
+    ///
+    /// If the set has no booster packs, it will return an error.
+    pub fn for_set(
+        set: &SetDetail,
+        cards: Vec<CardDetail>,
+        seed: u64,
+    ) -> Result<BoosterGenerator, Error> {
+        if set.booster.is_empty() {
+            return Err(MtgApiErrorKind::NoBoosterTemplate {
+                set: set.code.to_string(),
+            }
+            .into());
+        }
+        Ok(BoosterGenerator::new(set.booster.clone(), cards, seed))
+    }
+
+    /// Returns the booster template the packs are opened from
+    pub fn template(&self) -> &BoosterTemplate {
+        &self.template
+    }
+
+    /// Opens a single pack
+    ///
+    /// Every slot is filled from one of its sheets according to the weights of the sheets.
+    /// Sheets without matching cards are skipped and a card appears at most once per pack.
+    /// Marketing inserts, checklists and tokens are left out.
+    pub fn open(&mut self) -> Vec<CardDetail> {
+        let mut pack: Vec<CardDetail> = Vec::new();
+        let mut opened: HashSet<String> = HashSet::new();
+        for slot in self.template.slots.iter().filter(|slot| slot.holds_card()) {
+            if let Some(card) = open_slot(slot, &self.cards, &opened, &mut self.rng) {
+                opened.insert(card.name.clone());
+                pack.push(card.clone());
+            }
+        }
+        pack
+    }
+
+    /// Opens the number of packs
+    pub fn open_many(&mut self, count: usize) -> Vec<Vec<CardDetail>> {
+        (0..count).map(|_| self.open()).collect()
+    }
+}
+
+/// Picks a sheet by its weight among the sheets with cards left and a card of the sheet
+fn open_slot<'a, R: Rng>(
+    slot: &BoosterSlot,
+    cards: &'a [CardDetail],
+    opened: &HashSet<String>,
+    rng: &mut R,
+) -> Option<&'a CardDetail> {
+    let candidates: Vec<(&SlotAlternative, Vec<&CardDetail>)> = slot
+        .alternatives
+        .iter()
+        .map(|alternative| {
+            let sheet: Vec<&CardDetail> = cards
+                .iter()
+                .filter(|card| on_sheet(card, alternative) && !opened.contains(&card.name))
+                .collect();
+            (alternative, sheet)
+        })
+        .filter(|(_, sheet)| !sheet.is_empty())
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+    let total: f64 = candidates
+        .iter()
+        .map(|(alternative, _)| alternative.weight)
+        .sum();
+    let mut roll = rng.gen::<f64>() * total;
+    for (alternative, sheet) in &candidates {
+        if roll < alternative.weight {
+            return Some(sheet[rng.gen_range(0..sheet.len())]);
+        }
+        roll -= alternative.weight;
+    }
+    let (_, sheet) = &candidates[candidates.len() - 1];
+    Some(sheet[rng.gen_range(0..sheet.len())])
+}
+
+/// Returns true if the card can be opened from the sheet
+///
+/// Timeshifted cards are only opened from timeshifted sheets,
+/// sheets without a rarity are matched by their name, e.g. `double faced`.
+/// A plain `foil` sheet holds every card of the set except the basic lands.
+fn on_sheet(card: &CardDetail, alternative: &SlotAlternative) -> bool {
+    let sheet = alternative.sheet.to_lowercase();
+    if card.timeshifted != sheet.contains(TIMESHIFTED) {
+        return false;
+    }
+    match &alternative.rarity {
+        Some(CardRarity::BasicLand) => is_basic_land(card),
+        Some(rarity) => card.rarity == *rarity && !is_basic_land(card),
+        None if sheet.contains(DOUBLE_FACED) => matches!(
+            card.layout,
+            Some(CardLayout::DoubleFaced)
+                | Some(CardLayout::Transform)
+                | Some(CardLayout::ModalDoubleFaced)
+        ),
+        None if sheet.trim() == FOIL => !is_basic_land(card),
+        None => card.timeshifted,
+    }
+}
+
+fn is_basic_land(card: &CardDetail) -> bool {
+    card.rarity == CardRarity::BasicLand
+        || (card.supertypes.iter().any(|supertype| supertype == "Basic")
+            && card.types.iter().any(|card_type| card_type == "Land"))
+}
+
+/// Back faces and the second halves of split cards are opened together with their front face
+fn is_front_face(card: &CardDetail) -> bool {
+    match card.names.first() {
+        Some(name) => *name == card.name,
+        None => true,
+    }
+}
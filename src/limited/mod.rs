@@ -0,0 +1,3 @@
+pub mod draft;
+pub mod generator;
+pub mod sealed;
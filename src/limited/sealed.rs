@@ -0,0 +1,154 @@
+use crate::api::card::filtertypes::{CardColor, CardRarity};
+use crate::model::card::CardDetail;
+use crate::model::mana::ManaColor;
+use std::collections::{BTreeMap, HashMap};
+
+/// The default number of packs fetched at the same time
+pub const DEFAULT_PACK_CONCURRENCY: usize = 4;
+
+/// The card types a card is grouped by, the first type the card has is used
+const TYPE_ORDER: &[&str] = &[
+    "Creature",
+    "Planeswalker",
+    "Battle",
+    "Land",
+    "Instant",
+    "Sorcery",
+    "Enchantment",
+    "Artifact",
+];
+
+/// Where the packs are opened
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PackSource {
+    /// Every pack is a call to the booster endpoint of the API
+    Api,
+    /// The packs are opened locally from the booster template and the cards of the set
+    Local { seed: u64 },
+}
+
+/// Options for opening several packs
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct PackOptions {
+    pub source: PackSource,
+    /// The maximum number of requests running at the same time
+    pub concurrency: usize,
+}
+
+impl Default for PackOptions {
+    fn default() -> PackOptions {
+        PackOptions {
+            source: PackSource::Api,
+            concurrency: DEFAULT_PACK_CONCURRENCY,
+        }
+    }
+}
+
+/// The color group of a card in a pool
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ColorGroup {
+    Mono(ManaColor),
+    Multicolor,
+    Colorless,
+}
+
+impl ColorGroup {
+    /// Returns the color group of the card
+    pub fn of(card: &CardDetail) -> ColorGroup {
+        let colors: Vec<ManaColor> = ManaColor::values()
+            .iter()
+            .cloned()
+            .filter(|color| card.colors.contains(&CardColor::from(*color)))
+            .collect();
+        match colors.as_slice() {
+            [] => ColorGroup::Colorless,
+            [color] => ColorGroup::Mono(*color),
+            _ => ColorGroup::Multicolor,
+        }
+    }
+}
+
+/// The cards of several opened packs, e.g. a sealed pool or a booster box
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::model::mana::ManaColor;
+/// # use mtgapi_client::limited::sealed::*;
+/// let card = |name: &str, colors: Vec<CardColor>, types: &[&str], rarity: CardRarity| CardDetail {
+///     name: name.to_string(),
+///     colors,
+///     types: types.iter().map(|card_type| card_type.to_string()).collect(),
+///     rarity,
+///     ..Default::default()
+/// };
+/// let pool = SealedPool::new(vec![
+///     vec![
+///         card("Shock", vec![CardColor::Red], &["Instant"], CardRarity::Common),
+///         card("Ornithopter", vec![], &["Artifact", "Creature"], CardRarity::Uncommon),
+///     ],
+///     vec![card("Electrolyze", vec![CardColor::Blue, CardColor::Red], &["Instant"], CardRarity::Uncommon)],
+/// ]);
+/// assert_eq!(pool.len(), 3);
+/// assert_eq!(pool.by_color()[&ColorGroup::Mono(ManaColor::Red)].len(), 1);
+/// assert_eq!(pool.by_rarity()[&CardRarity::Uncommon].len(), 2);
+/// assert_eq!(pool.by_type()["Creature"][0].name, "Ornithopter");
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct SealedPool {
+    pub packs: Vec<Vec<CardDetail>>,
+}
+
+impl SealedPool {
+    pub fn new(packs: Vec<Vec<CardDetail>>) -> SealedPool {
+        SealedPool { packs }
+    }
+
+    /// Returns all cards of the pool in the order they were opened
+    pub fn cards(&self) -> impl Iterator<Item = &CardDetail> {
+        self.packs.iter().flatten()
+    }
+
+    /// Returns the number of cards in the pool
+    pub fn len(&self) -> usize {
+        self.packs.iter().map(Vec::len).sum()
+    }
+
+    /// Returns true if the pool holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Groups the cards by color in WUBRG order, followed by multicolored and colorless cards
+    pub fn by_color(&self) -> BTreeMap<ColorGroup, Vec<&CardDetail>> {
+        let mut groups: BTreeMap<ColorGroup, Vec<&CardDetail>> = BTreeMap::new();
+        for card in self.cards() {
+            groups.entry(ColorGroup::of(card)).or_default().push(card);
+        }
+        groups
+    }
+
+    /// Groups the cards by rarity
+    pub fn by_rarity(&self) -> HashMap<CardRarity, Vec<&CardDetail>> {
+        let mut groups: HashMap<CardRarity, Vec<&CardDetail>> = HashMap::new();
+        for card in self.cards() {
+            groups.entry(card.rarity.clone()).or_default().push(card);
+        }
+        groups
+    }
+
+    /// Groups the cards by their main type, e.g. artifact creatures are grouped as `Creature`
+    pub fn by_type(&self) -> HashMap<String, Vec<&CardDetail>> {
+        let mut groups: HashMap<String, Vec<&CardDetail>> = HashMap::new();
+        for card in self.cards() {
+            let main_type = TYPE_ORDER
+                .iter()
+                .find(|main_type| card.types.iter().any(|card_type| card_type == *main_type))
+                .map(|main_type| main_type.to_string())
+                .or_else(|| card.types.first().cloned())
+                .unwrap_or_default();
+            groups.entry(main_type).or_default().push(card);
+        }
+        groups
+    }
+}
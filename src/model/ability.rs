@@ -0,0 +1,399 @@
+use crate::api::card::filtertypes::CardType;
+use crate::model::card::CardDetail;
+use crate::model::keyword::{
+    parse_keyword_line, starts_with_ignore_case, strip_reminder_text, KeywordAbility,
+};
+use crate::model::mana::ManaCost;
+use crate::model::stat::StatValue;
+
+const TRIGGER_WORDS: &[(&str, TriggerKind)] = &[
+    ("Whenever ", TriggerKind::Whenever),
+    ("When ", TriggerKind::When),
+    ("At ", TriggerKind::At),
+];
+const BULLETS: &[char] = &['•', '*'];
+const DASHES: &[char] = &['—', '–'];
+const MINUS_SIGNS: &[char] = &['−', '-', '–'];
+
+/// The word a triggered ability starts with
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TriggerKind {
+    When,
+    Whenever,
+    At,
+}
+
+/// The change of loyalty of a loyalty ability, e.g. `+1`, `−2`, `0` or `−X`
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct LoyaltyChange {
+    /// `1` for `+`, `-1` for `−` and `0` for an unsigned value
+    pub sign: i32,
+    pub amount: StatValue,
+}
+
+/// The cost of an activated or loyalty ability, split into its components
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct AbilityCost {
+    pub mana: Option<ManaCost>,
+    /// `{T}` is part of the cost
+    pub tap: bool,
+    /// `{Q}` is part of the cost
+    pub untap: bool,
+    /// What has to be sacrificed, e.g. `a creature`
+    pub sacrifice: Vec<String>,
+    /// What has to be discarded, e.g. `a card`
+    pub discard: Vec<String>,
+    pub loyalty: Option<LoyaltyChange>,
+    /// Components that are not parsed, e.g. `Pay 2 life`
+    pub other: Vec<String>,
+}
+
+impl AbilityCost {
+    /// Parses a cost like `{2}{U}, {T}, Sacrifice a creature`
+    ///
+    /// ```
+    /// # use mtgapi_client::model::ability::AbilityCost;
+    /// let cost = AbilityCost::parse("{2}{U}, {T}, Sacrifice a creature, Pay 2 life");
+    /// assert_eq!(cost.mana.unwrap().to_string(), "{2}{U}");
+    /// assert!(cost.tap);
+    /// assert_eq!(cost.sacrifice, vec!["a creature"]);
+    /// assert_eq!(cost.other, vec!["Pay 2 life"]);
+    /// ```
+    pub fn parse(cost: &str) -> AbilityCost {
+        let mut parsed = AbilityCost::default();
+        for part in cost
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+        {
+            if let Some(loyalty) = parse_loyalty(part) {
+                parsed.loyalty = Some(loyalty);
+            } else if part.starts_with('{') && part.ends_with('}') {
+                parsed.add_symbols(part);
+            } else if starts_with_ignore_case(part, "Sacrifice ") {
+                parsed
+                    .sacrifice
+                    .push(part["Sacrifice ".len()..].to_string());
+            } else if starts_with_ignore_case(part, "Discard ") {
+                parsed.discard.push(part["Discard ".len()..].to_string());
+            } else {
+                parsed.other.push(part.to_string());
+            }
+        }
+        parsed
+    }
+
+    fn add_symbols(&mut self, symbols: &str) {
+        let mut mana = String::new();
+        for symbol in symbols.split_inclusive('}') {
+            match symbol.trim() {
+                "{T}" => self.tap = true,
+                "{Q}" => self.untap = true,
+                symbol => mana.push_str(symbol),
+            }
+        }
+        if mana.is_empty() {
+            return;
+        }
+        match mana.parse::<ManaCost>() {
+            Ok(cost) => self.mana = Some(cost),
+            Err(_) => self.other.push(mana),
+        }
+    }
+}
+
+/// A single ability of the oracle text
+#[derive(Clone, PartialEq, Debug)]
+pub enum Ability {
+    /// A line of keyword abilities, e.g. `Flying, lifelink`
+    Keywords(Vec<KeywordAbility>),
+    /// `cost: effect`
+    Activated { cost: AbilityCost, effect: String },
+    /// `When/Whenever/At condition, effect`
+    Triggered {
+        trigger: TriggerKind,
+        condition: String,
+        effect: String,
+    },
+    /// An ability that is always true, e.g. `Creatures you control get +1/+1.`
+    Static(String),
+    /// The effect of an instant or sorcery
+    Spell(String),
+    /// A planeswalker loyalty ability, the cost contains the loyalty change
+    Loyalty { cost: AbilityCost, effect: String },
+    /// A saga chapter ability, e.g. `I, II — effect`
+    Chapter { chapters: Vec<u32>, effect: String },
+    /// A level band of a leveler, e.g. `LEVEL 1-3` followed by `2/3` and abilities
+    Level {
+        min: u32,
+        max: Option<u32>,
+        power: Option<StatValue>,
+        toughness: Option<StatValue>,
+        abilities: Vec<Ability>,
+    },
+    /// A modal ability, e.g. `Choose one —` followed by bullets
+    Modal { header: String, modes: Vec<String> },
+}
+
+/// Parses oracle text into its abilities
+///
+/// Lines that are not keywords, activated, triggered, loyalty, chapter, level or modal
+/// abilities are static abilities, or spell effects if `spell` is set.
+/// Ability words like `Landfall —` and reminder text are dropped.
+///
+/// ```
+/// # use mtgapi_client::model::ability::*;
+/// let text = "Flying\n{1}{G}, {T}: Draw a card.\nWhenever a creature dies, you gain 1 life.\n\
+///     Creatures you control get +1/+1.\nChoose one —\n• Destroy target artifact.\n• Destroy target enchantment.";
+/// let abilities = parse_abilities(text, false);
+/// assert_eq!(abilities.len(), 5);
+/// match &abilities[1] {
+///     Ability::Activated { cost, effect } => {
+///         assert!(cost.tap);
+///         assert_eq!(effect, "Draw a card.");
+///     }
+///     _ => panic!("expected an activated ability"),
+/// }
+/// match &abilities[2] {
+///     Ability::Triggered { trigger, condition, .. } => {
+///         assert_eq!(*trigger, TriggerKind::Whenever);
+///         assert_eq!(condition, "a creature dies");
+///     }
+///     _ => panic!("expected a triggered ability"),
+/// }
+/// assert_eq!(abilities[3], Ability::Static("Creatures you control get +1/+1.".to_string()));
+/// match &abilities[4] {
+///     Ability::Modal { modes, .. } => assert_eq!(modes.len(), 2),
+///     _ => panic!("expected a modal ability"),
+/// }
+///
+/// let abilities = parse_abilities("+1: Draw a card.\n−3: Destroy target creature.", false);
+/// match &abilities[1] {
+///     Ability::Loyalty { cost, .. } => assert_eq!(cost.loyalty.as_ref().unwrap().sign, -1),
+///     _ => panic!("expected a loyalty ability"),
+/// }
+///
+/// let abilities = parse_abilities("I, II — Create a 1/1 Soldier token.\nIII — Draw a card.", false);
+/// assert_eq!(abilities[0], Ability::Chapter { chapters: vec![1, 2], effect: "Create a 1/1 Soldier token.".to_string() });
+/// ```
+pub fn parse_abilities(text: &str, spell: bool) -> Vec<Ability> {
+    let text = strip_reminder_text(text);
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let mut abilities = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+        if let Some((min, max)) = parse_level(line) {
+            let mut band_lines = Vec::new();
+            while index < lines.len() && parse_level(lines[index]).is_none() {
+                band_lines.push(lines[index]);
+                index += 1;
+            }
+            abilities.push(parse_level_band(min, max, &band_lines, spell));
+        } else if is_modal_header(line) {
+            let mut modes = Vec::new();
+            while index < lines.len() && lines[index].starts_with(BULLETS) {
+                modes.push(lines[index].trim_start_matches(BULLETS).trim().to_string());
+                index += 1;
+            }
+            abilities.push(Ability::Modal {
+                header: line.to_string(),
+                modes,
+            });
+        } else {
+            abilities.push(parse_line(line, spell));
+        }
+    }
+    abilities
+}
+
+impl CardDetail {
+    /// Parses the text of the card into its abilities
+    ///
+    /// The text of instants and sorceries is parsed as spell effects,
+    /// the level bands of levelers are parsed into `Ability::Level`.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::ability::Ability;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::stat::StatValue;
+    /// let card = CardDetail {
+    ///     name: "Student of Warfare".to_string(),
+    ///     text: "Level up {W}\nLEVEL 2-6\n3/3\nFirst strike\nLEVEL 7+\n4/4\nDouble strike".to_string(),
+    ///     layout: Some(CardLayout::Leveler),
+    ///     ..Default::default()
+    /// };
+    /// let abilities = card.abilities();
+    /// assert_eq!(abilities.len(), 3);
+    /// match &abilities[2] {
+    ///     Ability::Level { min, max, power, .. } => {
+    ///         assert_eq!((*min, *max), (7, None));
+    ///         assert_eq!(*power, Some(StatValue::Number(4)));
+    ///     }
+    ///     _ => panic!("expected a level band"),
+    /// }
+    /// ```
+    pub fn abilities(&self) -> Vec<Ability> {
+        let spell = self.types.iter().any(|card_type| {
+            let card_type = CardType::from(card_type.as_str());
+            card_type == CardType::Instant || card_type == CardType::Sorcery
+        });
+        parse_abilities(&self.text, spell)
+    }
+}
+
+fn parse_line(line: &str, spell: bool) -> Ability {
+    if let Some(keywords) = parse_keyword_line(line) {
+        return Ability::Keywords(keywords);
+    }
+    if let Some(chapter) = parse_chapter(line) {
+        return chapter;
+    }
+    let line = strip_ability_word(line);
+    if let Some((cost, effect)) = split_cost(line) {
+        let cost = AbilityCost::parse(cost);
+        let effect = effect.to_string();
+        return match cost.loyalty {
+            Some(_) => Ability::Loyalty { cost, effect },
+            None => Ability::Activated { cost, effect },
+        };
+    }
+    for (word, trigger) in TRIGGER_WORDS {
+        if let Some(rest) = line.strip_prefix(word) {
+            let (condition, effect) = match rest.find(", ") {
+                Some(index) => (&rest[..index], &rest[index + 2..]),
+                None => (rest, ""),
+            };
+            return Ability::Triggered {
+                trigger: *trigger,
+                condition: condition.trim().to_string(),
+                effect: effect.trim().to_string(),
+            };
+        }
+    }
+    if spell {
+        Ability::Spell(line.to_string())
+    } else {
+        Ability::Static(line.to_string())
+    }
+}
+
+/// Splits `cost: effect`, colons inside quotes belong to granted abilities
+fn split_cost(line: &str) -> Option<(&str, &str)> {
+    let colon = line.find(": ")?;
+    let cost = &line[..colon];
+    if cost.contains('"') || cost.split_whitespace().count() > 12 {
+        return None;
+    }
+    let starts_like_cost = cost.starts_with('{')
+        || parse_loyalty(cost).is_some()
+        || cost
+            .split(',')
+            .any(|part| part.trim().starts_with(char::is_uppercase));
+    if !starts_like_cost || TRIGGER_WORDS.iter().any(|(word, _)| cost.starts_with(word)) {
+        return None;
+    }
+    Some((cost.trim(), line[colon + 2..].trim()))
+}
+
+fn parse_loyalty(cost: &str) -> Option<LoyaltyChange> {
+    let cost = cost.trim().trim_start_matches('[').trim_end_matches(']');
+    let (sign, amount) = if let Some(amount) = cost.strip_prefix('+') {
+        (1, amount)
+    } else if cost.starts_with(MINUS_SIGNS) {
+        (-1, cost.trim_start_matches(MINUS_SIGNS))
+    } else {
+        (0, cost)
+    };
+    let amount = match amount {
+        "X" => StatValue::X,
+        amount => StatValue::Number(amount.parse::<i32>().ok()?),
+    };
+    if sign == 0 && amount != StatValue::Number(0) {
+        return None;
+    }
+    Some(LoyaltyChange { sign, amount })
+}
+
+fn parse_chapter(line: &str) -> Option<Ability> {
+    let dash = line.find(DASHES)?;
+    let chapters = line[..dash]
+        .split(',')
+        .map(|chapter| roman_numeral(chapter.trim()))
+        .collect::<Option<Vec<u32>>>()?;
+    let effect = line[dash..].trim_start_matches(DASHES).trim().to_string();
+    Some(Ability::Chapter { chapters, effect })
+}
+
+fn roman_numeral(numeral: &str) -> Option<u32> {
+    const NUMERALS: &[&str] = &["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"];
+    NUMERALS
+        .iter()
+        .position(|entry| *entry == numeral)
+        .map(|position| position as u32 + 1)
+}
+
+fn parse_level(line: &str) -> Option<(u32, Option<u32>)> {
+    let range = line.strip_prefix("LEVEL ")?.trim();
+    if let Some(min) = range.strip_suffix('+') {
+        return Some((min.trim().parse().ok()?, None));
+    }
+    let mut bounds = range.splitn(2, ['-', '–']);
+    let min = bounds.next()?.trim().parse().ok()?;
+    let max = bounds.next()?.trim().parse().ok()?;
+    Some((min, Some(max)))
+}
+
+fn parse_level_band(min: u32, max: Option<u32>, lines: &[&str], spell: bool) -> Ability {
+    let stats = lines.first().and_then(|line| {
+        let mut stats = line.splitn(2, '/');
+        match (stats.next(), stats.next()) {
+            (Some(power), Some(toughness))
+                if !power.contains(' ') && !toughness.trim().contains(' ') =>
+            {
+                Some((StatValue::from(power), StatValue::from(toughness)))
+            }
+            _ => None,
+        }
+    });
+    let ability_lines = if stats.is_some() { &lines[1..] } else { lines };
+    let (power, toughness) = match stats {
+        Some((power, toughness)) => (Some(power), Some(toughness)),
+        None => (None, None),
+    };
+    Ability::Level {
+        min,
+        max,
+        power,
+        toughness,
+        abilities: ability_lines
+            .iter()
+            .map(|line| parse_line(line, spell))
+            .collect(),
+    }
+}
+
+fn is_modal_header(line: &str) -> bool {
+    starts_with_ignore_case(line, "Choose ") && line.trim_end().ends_with(DASHES)
+}
+
+/// Drops ability words like `Landfall — `, they have no rules meaning
+fn strip_ability_word(line: &str) -> &str {
+    match line.find(DASHES) {
+        Some(index)
+            if index > 0
+                && line[..index].split_whitespace().count() <= 3
+                && line.starts_with(char::is_uppercase)
+                && !line[..index].contains([':', ',', '{']) =>
+        {
+            line[index..].trim_start_matches(DASHES).trim()
+        }
+        _ => line,
+    }
+}
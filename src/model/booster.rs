@@ -0,0 +1,225 @@
+use crate::api::card::filtertypes::CardRarity;
+use crate::model::set::Booster;
+
+const FOIL: &str = "foil";
+const FOIL_PREFIX: &str = "foil ";
+const TIMESHIFTED_PREFIX: &str = "timeshifted ";
+/// Sheets that don't contain playable cards
+const NON_CARD_SHEETS: &[&str] = &["marketing", "checklist", "token"];
+/// How often a card of the rarity is opened relative to the other rarities,
+/// e.g. a rare slot holds a mythic rare in one of eight packs
+const RARITY_WEIGHTS: &[(CardRarity, f64)] = &[
+    (CardRarity::Common, 80.0),
+    (CardRarity::Uncommon, 24.0),
+    (CardRarity::Rare, 7.0),
+    (CardRarity::MythicRare, 1.0),
+];
+
+/// One of the sheets a booster slot can be filled from
+#[derive(Clone, PartialEq, Debug)]
+pub struct SlotAlternative {
+    /// The sheet as returned by the API, e.g. `mythic rare` or `foil common`
+    pub sheet: String,
+    /// The rarity of the cards on the sheet, if the sheet is made of a single rarity
+    pub rarity: Option<CardRarity>,
+    pub foil: bool,
+    /// The inferred probability of this alternative within its slot
+    pub weight: f64,
+}
+
+/// A slot of a booster pack holding one card
+#[derive(Clone, PartialEq, Debug)]
+pub struct BoosterSlot {
+    pub alternatives: Vec<SlotAlternative>,
+}
+
+impl BoosterSlot {
+    /// Creates a slot from its sheets and infers the weights of the alternatives
+    ///
+    /// The weights follow the usual distribution of the rarities.
+    /// Sheets without a single rarity, e.g. `foil`, are as likely as the average sheet
+    /// with a rarity, or all equally likely if no sheet of the slot has a rarity.
+    ///
+    /// ```
+    /// # use mtgapi_client::model::booster::BoosterSlot;
+    /// let sheets = vec!["rare".to_string(), "mythic rare".to_string(), "foil".to_string()];
+    /// let slot = BoosterSlot::new(&sheets);
+    /// let weights: Vec<f64> = slot.alternatives.iter().map(|alternative| alternative.weight).collect();
+    /// assert_eq!(weights, vec![7.0 / 12.0, 1.0 / 12.0, 4.0 / 12.0]);
+    /// assert!(slot.alternatives[2].foil);
+    /// ```
+    pub fn new(sheets: &[String]) -> BoosterSlot {
+        let mut alternatives: Vec<SlotAlternative> = sheets
+            .iter()
+            .map(|sheet| {
+                let lowercase = sheet.trim().to_lowercase();
+                let foil = lowercase == FOIL || lowercase.starts_with(FOIL_PREFIX);
+                let rarity = sheet_rarity(sheet);
+                let weight = match &rarity {
+                    Some(rarity) => RARITY_WEIGHTS
+                        .iter()
+                        .find(|(known, _)| known == rarity)
+                        .map_or(1.0, |(_, weight)| *weight),
+                    None => 0.0,
+                };
+                SlotAlternative {
+                    sheet: sheet.clone(),
+                    rarity,
+                    foil,
+                    weight,
+                }
+            })
+            .collect();
+        let rarity_weights: Vec<f64> = alternatives
+            .iter()
+            .filter(|alternative| alternative.rarity.is_some())
+            .map(|alternative| alternative.weight)
+            .collect();
+        let average = match rarity_weights.len() {
+            0 => 1.0,
+            count => rarity_weights.iter().sum::<f64>() / count as f64,
+        };
+        alternatives
+            .iter_mut()
+            .filter(|alternative| alternative.rarity.is_none())
+            .for_each(|alternative| alternative.weight = average);
+        let total: f64 = alternatives
+            .iter()
+            .map(|alternative| alternative.weight)
+            .sum();
+        alternatives
+            .iter_mut()
+            .for_each(|alternative| alternative.weight /= total);
+        BoosterSlot { alternatives }
+    }
+
+    /// Returns false for slots holding marketing inserts, checklists or tokens
+    pub fn holds_card(&self) -> bool {
+        !self.alternatives.iter().all(|alternative| {
+            NON_CARD_SHEETS
+                .iter()
+                .any(|sheet| alternative.sheet.eq_ignore_ascii_case(sheet))
+        })
+    }
+
+    /// Returns true if the slot is always filled with a foil card
+    pub fn is_foil(&self) -> bool {
+        !self.alternatives.is_empty()
+            && self.alternatives.iter().all(|alternative| alternative.foil)
+    }
+
+    /// Returns the rarities that can appear in the slot
+    pub fn rarities(&self) -> Vec<CardRarity> {
+        let mut rarities: Vec<CardRarity> = Vec::new();
+        for rarity in self
+            .alternatives
+            .iter()
+            .filter_map(|alternative| alternative.rarity.clone())
+        {
+            if !rarities.contains(&rarity) {
+                rarities.push(rarity);
+            }
+        }
+        rarities
+    }
+}
+
+/// The slots of a booster pack of a set
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::booster::BoosterTemplate;
+/// # use mtgapi_client::model::set::Booster;
+/// let mut booster = vec![Booster::Multiple(vec!["rare".to_string(), "mythic rare".to_string()])];
+/// booster.extend(vec![Booster::Single("uncommon".to_string()); 3]);
+/// booster.extend(vec![Booster::Single("common".to_string()); 10]);
+/// booster.push(Booster::Single("land".to_string()));
+/// booster.push(Booster::Single("marketing".to_string()));
+///
+/// let template = BoosterTemplate::from(booster);
+/// assert_eq!(template.pack_size(), 15);
+/// assert_eq!(template.slots[0].alternatives[1].weight, 0.125);
+/// assert!(template.can_contain(&CardRarity::MythicRare));
+/// assert_eq!(
+///     template.rarities(),
+///     vec![CardRarity::Rare, CardRarity::MythicRare, CardRarity::Uncommon,
+///          CardRarity::Common, CardRarity::BasicLand]
+/// );
+/// ```
+#[derive(Clone, PartialEq, Default, Debug, Serialize, Deserialize)]
+#[serde(from = "Vec<Booster>", into = "Vec<Booster>")]
+pub struct BoosterTemplate {
+    pub slots: Vec<BoosterSlot>,
+}
+
+impl BoosterTemplate {
+    /// Returns the number of cards in a pack, marketing inserts, checklists and tokens excluded
+    pub fn pack_size(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.holds_card()).count()
+    }
+
+    /// Returns the rarities that can appear in a pack, in the order of the slots
+    pub fn rarities(&self) -> Vec<CardRarity> {
+        let mut rarities: Vec<CardRarity> = Vec::new();
+        for rarity in self.slots.iter().flat_map(BoosterSlot::rarities) {
+            if !rarities.contains(&rarity) {
+                rarities.push(rarity);
+            }
+        }
+        rarities
+    }
+
+    /// Returns true if a card of the rarity can appear in a pack
+    pub fn can_contain(&self, rarity: &CardRarity) -> bool {
+        self.rarities().contains(rarity)
+    }
+
+    /// Returns true if the set has no booster packs
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+}
+
+impl From<Vec<Booster>> for BoosterTemplate {
+    fn from(booster: Vec<Booster>) -> BoosterTemplate {
+        let slots = booster
+            .into_iter()
+            .map(|slot| match slot {
+                Booster::Single(sheet) => BoosterSlot::new(&[sheet]),
+                Booster::Multiple(sheets) => BoosterSlot::new(&sheets),
+            })
+            .collect();
+        BoosterTemplate { slots }
+    }
+}
+
+impl From<BoosterTemplate> for Vec<Booster> {
+    fn from(template: BoosterTemplate) -> Vec<Booster> {
+        template
+            .slots
+            .into_iter()
+            .map(|slot| {
+                let mut sheets: Vec<String> = slot
+                    .alternatives
+                    .into_iter()
+                    .map(|alternative| alternative.sheet)
+                    .collect();
+                match sheets.len() {
+                    1 => Booster::Single(sheets.remove(0)),
+                    _ => Booster::Multiple(sheets),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The rarity of a sheet like `rare`, `foil mythic rare`, `timeshifted common` or `land`
+fn sheet_rarity(sheet: &str) -> Option<CardRarity> {
+    let sheet = sheet.trim().to_lowercase();
+    let sheet = sheet.strip_prefix(FOIL_PREFIX).unwrap_or(&sheet);
+    let sheet = sheet.strip_prefix(TIMESHIFTED_PREFIX).unwrap_or(sheet);
+    match sheet {
+        "land" => Some(CardRarity::BasicLand),
+        _ => sheet.parse().ok(),
+    }
+}
@@ -1,3 +1,13 @@
+use crate::api::card::filtertypes::{
+    CardColor, CardColorIdentity, CardLanguage, CardLayout, CardLegality, CardRarity,
+};
+use crate::model::collector::CollectorNumber;
+use crate::model::ids::{release_date, CardId, MultiverseId, SetCode};
+use crate::model::mana::ManaCost;
+use crate::model::stat::StatValue;
+use chrono::NaiveDate;
+use failure::Error;
+
 ///DTO for the endpoints returning multiple cards
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(deny_unknown_fields, untagged)]
@@ -24,7 +34,31 @@ pub(crate) enum CardDto {
     },
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// A card as returned by the API
+///
+/// Colors, rarity, layout, legalities and languages are deserialized into the filter types,
+/// values unknown to this version of the client are kept as `Other`.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use chrono::NaiveDate;
+/// let card: CardDetail = serde_json::from_str(r#"{
+///     "name": "Student of Warfare", "cmc": 1.0, "colors": ["White"], "colorIdentity": ["W"],
+///     "type": "Creature — Human Knight", "rarity": "Rare", "set": "ROE", "artist": "Volkan Baga",
+///     "layout": "leveler", "legalities": [{"format": "Legacy", "legality": "Legal"}],
+///     "releaseDate": "2010-04", "id": "1"
+/// }"#).unwrap();
+/// assert_eq!(card.colors, vec![CardColor::White]);
+/// assert_eq!(card.color_identity, vec![CardColorIdentity::W]);
+/// assert_eq!(card.rarity, CardRarity::Rare);
+/// assert_eq!(card.layout, Some(CardLayout::Leveler));
+/// assert_eq!(card.legalities[0].legality, CardLegality::Legal);
+/// assert_eq!(card.set, "ROE");
+/// assert_eq!(card.release_date, NaiveDate::from_ymd_opt(2010, 4, 1));
+/// assert!(serde_json::to_string(&card).unwrap().contains(r#""releaseDate":"2010-04-01""#));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CardDetail {
     pub name: String,
@@ -33,9 +67,9 @@ pub struct CardDetail {
     pub mana_cost: Option<String>,
     pub cmc: f64,
     #[serde(default)]
-    pub colors: Vec<String>,
+    pub colors: Vec<CardColor>,
     #[serde(default)]
-    pub color_identity: Vec<String>,
+    pub color_identity: Vec<CardColorIdentity>,
     #[serde(rename = "type")]
     pub type_: String,
     #[serde(default)]
@@ -44,25 +78,31 @@ pub struct CardDetail {
     pub types: Vec<String>,
     #[serde(default)]
     pub subtypes: Vec<String>,
-    pub rarity: String,
-    pub set: String,
+    pub rarity: CardRarity,
+    pub set: SetCode,
     pub set_name: Option<String>,
     #[serde(default)]
     pub text: String,
     #[serde(default)]
     pub flavor: String,
     pub artist: String,
-    pub number: Option<String>,
-    pub power: Option<String>,
-    pub toughness: Option<String>,
-    pub layout: Option<String>,
-    pub loyalty: Option<u32>,
-    pub multiverseid: Option<u32>,
+    pub number: Option<CollectorNumber>,
+    pub power: Option<StatValue>,
+    pub toughness: Option<StatValue>,
+    pub layout: Option<CardLayout>,
+    pub loyalty: Option<StatValue>,
+    pub defense: Option<StatValue>,
+    pub multiverseid: Option<MultiverseId>,
     pub image_url: Option<String>,
     #[serde(default)]
     pub rulings: Vec<Ruling>,
     pub watermark: Option<String>,
-    pub release_date: Option<String>,
+    /// The release date, partial dates like `2018` are completed with the first day
+    ///
+    /// The completion is lossy: `2018` is serialized as `2018-01-01`.
+    /// Dates in an unknown format become `None`.
+    #[serde(default, with = "release_date")]
+    pub release_date: Option<NaiveDate>,
     #[serde(default)]
     pub foreign_names: Vec<ForeignName>,
     #[serde(default)]
@@ -83,28 +123,49 @@ pub struct CardDetail {
     #[serde(default)]
     pub starter: bool,
     pub source: Option<String>,
-    pub id: String,
+    pub id: CardId,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl CardDetail {
+    /// Parses the mana cost of the card, cards without a mana cost return `None`
+    ///
+    /// ```
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::mana::ManaColor;
+    /// let card = CardDetail {
+    ///     mana_cost: Some("{1}{B}{B}".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let cost = card.parsed_mana_cost().unwrap().unwrap();
+    /// assert_eq!(cost.devotion(ManaColor::Black), 2);
+    /// ```
+    pub fn parsed_mana_cost(&self) -> Result<Option<ManaCost>, Error> {
+        match &self.mana_cost {
+            Some(mana_cost) => Ok(Some(mana_cost.parse()?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct Ruling {
     pub date: String,
     pub text: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(deny_unknown_fields)]
 pub struct Legality {
     pub format: String,
-    pub legality: String,
+    pub legality: CardLegality,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ForeignName {
     pub image_url: Option<String>,
     pub name: String,
-    pub multiverseid: Option<u32>,
-    pub language: String,
+    pub multiverseid: Option<MultiverseId>,
+    pub language: CardLanguage,
 }
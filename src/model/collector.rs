@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Display;
+
+const REBALANCED_PREFIX: &str = "A-";
+const STAR: char = '★';
+const PROMO_SUFFIXES: &[&str] = &["p", "s"];
+
+/// The collector number of a card, e.g. `12`, `12a`, `★5`, `S12` or `A-123`
+///
+/// The number is split into a prefix, a numeric part and a suffix.
+/// Collector numbers are ordered naturally by the numeric part, then by prefix and suffix,
+/// numbers without a numeric part come last.
+///
+/// ```
+/// # use mtgapi_client::model::collector::CollectorNumber;
+/// let mut numbers: Vec<CollectorNumber> = ["A-123", "12a", "S12", "123", "9", "★5", "12"]
+///     .iter()
+///     .map(|number| CollectorNumber::from(*number))
+///     .collect();
+/// numbers.sort();
+/// let sorted: Vec<&str> = numbers.iter().map(CollectorNumber::as_str).collect();
+/// assert_eq!(sorted, vec!["★5", "9", "12", "12a", "S12", "123", "A-123"]);
+///
+/// let number = CollectorNumber::from("A-123");
+/// assert_eq!(number.prefix(), "A-");
+/// assert_eq!(number.number(), Some(123));
+/// assert!(number.is_rebalanced());
+/// assert!(CollectorNumber::from("250p").is_promo());
+/// assert!(!CollectorNumber::from("250").is_variant());
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default, Debug, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct CollectorNumber {
+    prefix: String,
+    number: Option<u32>,
+    suffix: String,
+    raw: String,
+}
+
+impl CollectorNumber {
+    /// Returns the collector number as printed
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the characters before the numeric part, e.g. `A-` or `S`
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the numeric part
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// Returns the characters after the numeric part, e.g. `a`, `s` or `★`
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns true if the collector number has a prefix or suffix
+    pub fn is_variant(&self) -> bool {
+        !self.prefix.is_empty() || !self.suffix.is_empty()
+    }
+
+    /// Returns true if the collector number marks a promo,
+    /// e.g. `250p` (promo pack), `250s` (prerelease) or `250★`
+    pub fn is_promo(&self) -> bool {
+        PROMO_SUFFIXES.contains(&self.suffix.as_str()) || self.raw.contains(STAR)
+    }
+
+    /// Returns true if the collector number marks a card rebalanced for digital play
+    pub fn is_rebalanced(&self) -> bool {
+        self.prefix == REBALANCED_PREFIX
+    }
+
+    /// Returns the face of a multi-face card marked by the suffix, e.g. `a` for `290a`
+    pub fn face(&self) -> Option<char> {
+        let mut chars = self.suffix.chars();
+        match (chars.next(), chars.next()) {
+            (Some(face), None) if face.is_ascii_lowercase() && !self.is_promo() => Some(face),
+            _ => None,
+        }
+    }
+
+    /// Returns the collector number without the face suffix, `290a` and `290b` become `290`
+    pub fn base(&self) -> CollectorNumber {
+        match self.face() {
+            Some(_) => {
+                let raw = self.raw.trim_end_matches(|c: char| c.is_ascii_lowercase());
+                CollectorNumber::from(raw)
+            }
+            None => self.clone(),
+        }
+    }
+}
+
+impl Ord for CollectorNumber {
+    fn cmp(&self, other: &CollectorNumber) -> Ordering {
+        let key = |number: &CollectorNumber| (number.number.is_none(), number.number);
+        key(self)
+            .cmp(&key(other))
+            .then_with(|| self.prefix.cmp(&other.prefix))
+            .then_with(|| self.suffix.cmp(&other.suffix))
+            .then_with(|| self.raw.cmp(&other.raw))
+    }
+}
+
+impl PartialOrd for CollectorNumber {
+    fn partial_cmp(&self, other: &CollectorNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for CollectorNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl From<&str> for CollectorNumber {
+    fn from(number: &str) -> CollectorNumber {
+        let raw = number.trim();
+        let start = raw.find(|c: char| c.is_ascii_digit()).unwrap_or(raw.len());
+        let end = raw[start..]
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(raw.len(), |end| start + end);
+        let numeric = raw[start..end].parse::<u32>().ok();
+        let (prefix, suffix) = match numeric {
+            Some(_) => (&raw[..start], &raw[end..]),
+            None => (raw, ""),
+        };
+        CollectorNumber {
+            prefix: prefix.to_string(),
+            number: numeric,
+            suffix: suffix.to_string(),
+            raw: raw.to_string(),
+        }
+    }
+}
+
+impl From<String> for CollectorNumber {
+    fn from(number: String) -> CollectorNumber {
+        CollectorNumber::from(number.as_str())
+    }
+}
+
+impl From<CollectorNumber> for String {
+    fn from(number: CollectorNumber) -> String {
+        number.raw
+    }
+}
@@ -0,0 +1,151 @@
+use crate::model::card::CardDetail;
+use crate::model::keyword::strip_reminder_text;
+use crate::model::mana::{ManaColor, ManaCost, BASIC_LAND_TYPES};
+use std::borrow::Borrow;
+
+/// A disagreement between the computed color identity and the one returned by the API
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct IdentityMismatch {
+    pub computed: Vec<ManaColor>,
+    /// Colors that are computed but missing in the API data
+    pub missing: Vec<ManaColor>,
+    /// Colors in the API data that are not computed
+    pub unexpected: Vec<ManaColor>,
+}
+
+impl CardDetail {
+    /// Computes the color identity of the card
+    ///
+    /// The identity contains the colors of the mana cost, of the mana symbols in the text
+    /// (reminder text is ignored), of the card itself (which covers color indicators)
+    /// and of its basic land types.
+    ///
+    /// ```
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::mana::ManaColor;
+    /// let card = CardDetail {
+    ///     mana_cost: Some("{2}{G}".to_string()),
+    ///     text: "{W/P}: Target creature gains vigilance.\n(Reminder: {B} doesn't count.)".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(card.computed_color_identity(), vec![ManaColor::White, ManaColor::Green]);
+    /// ```
+    pub fn computed_color_identity(&self) -> Vec<ManaColor> {
+        let mut colors: Vec<ManaColor> = Vec::new();
+        if let Ok(Some(cost)) = self.parsed_mana_cost() {
+            colors.extend(cost.colors());
+        }
+        colors.extend(text_colors(&self.text));
+        colors.extend(self.colors.iter().filter_map(ManaColor::from_card_color));
+        colors.extend(
+            BASIC_LAND_TYPES
+                .iter()
+                .filter(|(land_type, _)| self.subtypes.iter().any(|subtype| subtype == land_type))
+                .map(|(_, color)| *color),
+        );
+        colors.sort();
+        colors.dedup();
+        colors
+    }
+
+    /// Returns the color identity returned by the API, unknown values are dropped
+    pub fn api_color_identity(&self) -> Vec<ManaColor> {
+        let mut colors: Vec<ManaColor> = self
+            .color_identity
+            .iter()
+            .filter_map(ManaColor::from_identity)
+            .collect();
+        colors.sort();
+        colors.dedup();
+        colors
+    }
+
+    /// Compares the computed color identity with the one returned by the API
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::mana::ManaColor;
+    /// let card = CardDetail {
+    ///     mana_cost: Some("{1}{R}".to_string()),
+    ///     text: "{G}: Regenerate this creature.".to_string(),
+    ///     color_identity: vec![CardColorIdentity::R],
+    ///     ..Default::default()
+    /// };
+    /// let mismatch = card.check_color_identity().unwrap();
+    /// assert_eq!(mismatch.missing, vec![ManaColor::Green]);
+    /// assert!(mismatch.unexpected.is_empty());
+    /// ```
+    pub fn check_color_identity(&self) -> Option<IdentityMismatch> {
+        check_color_identity_of(Some(self))
+    }
+}
+
+/// Computes the color identity of all faces of a multi-face card
+pub fn color_identity_of<I>(faces: I) -> Vec<ManaColor>
+where
+    I: IntoIterator,
+    I::Item: Borrow<CardDetail>,
+{
+    let mut colors: Vec<ManaColor> = faces
+        .into_iter()
+        .flat_map(|face| face.borrow().computed_color_identity())
+        .collect();
+    colors.sort();
+    colors.dedup();
+    colors
+}
+
+/// Compares the computed color identity of all faces with the one returned by the API
+///
+/// Returns `None` if they agree.
+pub fn check_color_identity_of<I>(faces: I) -> Option<IdentityMismatch>
+where
+    I: IntoIterator,
+    I::Item: Borrow<CardDetail>,
+{
+    let faces: Vec<I::Item> = faces.into_iter().collect();
+    let computed = color_identity_of(faces.iter().map(|face| face.borrow()));
+    let mut api: Vec<ManaColor> = faces
+        .iter()
+        .flat_map(|face| face.borrow().api_color_identity())
+        .collect();
+    api.sort();
+    api.dedup();
+    let missing: Vec<ManaColor> = computed
+        .iter()
+        .cloned()
+        .filter(|color| !api.contains(color))
+        .collect();
+    let unexpected: Vec<ManaColor> = api
+        .iter()
+        .cloned()
+        .filter(|color| !computed.contains(color))
+        .collect();
+    if missing.is_empty() && unexpected.is_empty() {
+        return None;
+    }
+    Some(IdentityMismatch {
+        computed,
+        missing,
+        unexpected,
+    })
+}
+
+/// Collects the colors of all mana symbols in the text, ignoring reminder text
+fn text_colors(text: &str) -> Vec<ManaColor> {
+    let text = strip_reminder_text(text);
+    let mut colors = Vec::new();
+    let mut rest = text.as_str();
+    while let Some(start) = rest.find('{') {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        if let Ok(symbol) = rest[start..=end].parse::<ManaCost>() {
+            colors.extend(symbol.colors());
+        }
+        rest = &rest[end + 1..];
+    }
+    colors
+}
@@ -0,0 +1,204 @@
+use crate::model::card::CardDetail;
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serializer};
+use std::fmt;
+use std::fmt::Display;
+
+/// The format of full dates in the responses and filters of the API, e.g. `2018-04-27`
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// The id of a card printing returned by the API
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CardId(pub String);
+
+/// The id of a card printing in Gatherer
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct MultiverseId(pub u32);
+
+/// The code of a set, e.g. `DOM`
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SetCode(pub String);
+
+impl CardId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl SetCode {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Compares the set codes ignoring the case
+    pub fn eq_ignore_case(&self, code: &str) -> bool {
+        self.0.eq_ignore_ascii_case(code)
+    }
+}
+
+impl Display for CardId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for MultiverseId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for SetCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for CardId {
+    fn from(id: &str) -> CardId {
+        CardId(id.to_string())
+    }
+}
+
+impl From<String> for CardId {
+    fn from(id: String) -> CardId {
+        CardId(id)
+    }
+}
+
+impl From<u32> for MultiverseId {
+    fn from(id: u32) -> MultiverseId {
+        MultiverseId(id)
+    }
+}
+
+impl From<&str> for SetCode {
+    fn from(code: &str) -> SetCode {
+        SetCode(code.to_string())
+    }
+}
+
+impl From<String> for SetCode {
+    fn from(code: String) -> SetCode {
+        SetCode(code)
+    }
+}
+
+impl PartialEq<str> for SetCode {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for SetCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> From<&'a SetCode> for &'a str {
+    fn from(code: &'a SetCode) -> &'a str {
+        code.as_str()
+    }
+}
+
+/// The id a card is looked up by
+///
+/// ```
+/// # use mtgapi_client::model::ids::*;
+/// assert_eq!(CardLookup::from(409741), CardLookup::MultiverseId(MultiverseId(409741)));
+/// assert_eq!(CardLookup::from("5f8287b1").to_string(), "5f8287b1");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CardLookup {
+    Id(CardId),
+    MultiverseId(MultiverseId),
+}
+
+impl Display for CardLookup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CardLookup::Id(id) => write!(f, "{}", id),
+            CardLookup::MultiverseId(id) => write!(f, "{}", id),
+        }
+    }
+}
+
+impl From<CardId> for CardLookup {
+    fn from(id: CardId) -> CardLookup {
+        CardLookup::Id(id)
+    }
+}
+
+impl From<&CardId> for CardLookup {
+    fn from(id: &CardId) -> CardLookup {
+        CardLookup::Id(id.clone())
+    }
+}
+
+impl From<MultiverseId> for CardLookup {
+    fn from(id: MultiverseId) -> CardLookup {
+        CardLookup::MultiverseId(id)
+    }
+}
+
+impl From<u32> for CardLookup {
+    fn from(id: u32) -> CardLookup {
+        CardLookup::MultiverseId(MultiverseId(id))
+    }
+}
+
+impl From<&str> for CardLookup {
+    fn from(id: &str) -> CardLookup {
+        CardLookup::Id(CardId::from(id))
+    }
+}
+
+/// A card together with the id it was looked up by
+#[derive(Clone, Debug)]
+pub struct FoundCard {
+    pub lookup: CardLookup,
+    pub card: CardDetail,
+}
+
+/// Lenient (de)serialization of release dates like `2018-04-27`, `2018-04` or `2018`
+///
+/// Incomplete dates are completed with the first month or day, unknown formats become `None`.
+pub(crate) mod release_date {
+    use super::*;
+
+    pub fn parse(date: &str) -> Option<NaiveDate> {
+        let date = date.trim();
+        let parts: Vec<&str> = date.split('-').collect();
+        let number = |index: usize| parts.get(index).and_then(|part| part.parse::<u32>().ok());
+        match parts.len() {
+            1 => NaiveDate::from_ymd_opt(date.parse().ok()?, 1, 1),
+            2 => NaiveDate::from_ymd_opt(parts[0].parse().ok()?, number(1)?, 1),
+            3 => NaiveDate::parse_from_str(date, DATE_FORMAT).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let date: Option<String> = Option::deserialize(deserializer)?;
+        Ok(date.as_deref().and_then(parse))
+    }
+
+    pub fn serialize<S>(date: &Option<NaiveDate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => serializer.collect_str(&date.format(DATE_FORMAT)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use crate::model::card::CardDetail;
+use crate::model::mana::ManaCost;
+
+/// The kind of parameter a keyword ability takes
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParameterKind {
+    /// No parameter, e.g. `Flying`
+    None,
+    /// A cost, e.g. `Cycling {2}` or `Ward—Pay 3 life.`
+    Cost,
+    /// A number, e.g. `Toxic 1`
+    Number,
+    /// A quality after the connector, e.g. `Protection from red`
+    Quality(&'static str),
+    /// An optional quality after the connector, e.g. `Hexproof from white`
+    OptionalQuality(&'static str),
+}
+
+/// A keyword ability known to the extractor
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeywordDefinition {
+    pub name: &'static str,
+    pub parameter: ParameterKind,
+}
+
+const fn keyword(name: &'static str, parameter: ParameterKind) -> KeywordDefinition {
+    KeywordDefinition { name, parameter }
+}
+
+/// The catalog of keyword abilities known to the extractor
+pub const KEYWORDS: &[KeywordDefinition] = &[
+    keyword("Absorb", ParameterKind::Number),
+    keyword("Affinity", ParameterKind::Quality("for")),
+    keyword("Afflict", ParameterKind::Number),
+    keyword("Afterlife", ParameterKind::Number),
+    keyword("Amplify", ParameterKind::Number),
+    keyword("Annihilator", ParameterKind::Number),
+    keyword("Ascend", ParameterKind::None),
+    keyword("Backup", ParameterKind::Number),
+    keyword("Battle cry", ParameterKind::None),
+    keyword("Bestow", ParameterKind::Cost),
+    keyword("Blitz", ParameterKind::Cost),
+    keyword("Bloodthirst", ParameterKind::Number),
+    keyword("Bushido", ParameterKind::Number),
+    keyword("Buyback", ParameterKind::Cost),
+    keyword("Cascade", ParameterKind::None),
+    keyword("Casualty", ParameterKind::Number),
+    keyword("Changeling", ParameterKind::None),
+    keyword("Convoke", ParameterKind::None),
+    keyword("Crew", ParameterKind::Number),
+    keyword("Cycling", ParameterKind::Cost),
+    keyword("Dash", ParameterKind::Cost),
+    keyword("Daybound", ParameterKind::None),
+    keyword("Deathtouch", ParameterKind::None),
+    keyword("Decayed", ParameterKind::None),
+    keyword("Defender", ParameterKind::None),
+    keyword("Delve", ParameterKind::None),
+    keyword("Dethrone", ParameterKind::None),
+    keyword("Devoid", ParameterKind::None),
+    keyword("Devour", ParameterKind::Number),
+    keyword("Disturb", ParameterKind::Cost),
+    keyword("Double strike", ParameterKind::None),
+    keyword("Dredge", ParameterKind::Number),
+    keyword("Echo", ParameterKind::Cost),
+    keyword("Embalm", ParameterKind::Cost),
+    keyword("Emerge", ParameterKind::Cost),
+    keyword("Enchant", ParameterKind::Quality("")),
+    keyword("Encore", ParameterKind::Cost),
+    keyword("Enlist", ParameterKind::None),
+    keyword("Entwine", ParameterKind::Cost),
+    keyword("Equip", ParameterKind::Cost),
+    keyword("Escape", ParameterKind::Cost),
+    keyword("Eternalize", ParameterKind::Cost),
+    keyword("Evoke", ParameterKind::Cost),
+    keyword("Evolve", ParameterKind::None),
+    keyword("Exalted", ParameterKind::None),
+    keyword("Exploit", ParameterKind::None),
+    keyword("Extort", ParameterKind::None),
+    keyword("Fabricate", ParameterKind::Number),
+    keyword("Fading", ParameterKind::Number),
+    keyword("Fear", ParameterKind::None),
+    keyword("First strike", ParameterKind::None),
+    keyword("Flanking", ParameterKind::None),
+    keyword("Flash", ParameterKind::None),
+    keyword("Flashback", ParameterKind::Cost),
+    keyword("Flying", ParameterKind::None),
+    keyword("Forestwalk", ParameterKind::None),
+    keyword("Foretell", ParameterKind::Cost),
+    keyword("Fortify", ParameterKind::Cost),
+    keyword("Frenzy", ParameterKind::Number),
+    keyword("Graft", ParameterKind::Number),
+    keyword("Haste", ParameterKind::None),
+    keyword("Hexproof", ParameterKind::OptionalQuality("from")),
+    keyword("Hideaway", ParameterKind::Number),
+    keyword("Horsemanship", ParameterKind::None),
+    keyword("Improvise", ParameterKind::None),
+    keyword("Indestructible", ParameterKind::None),
+    keyword("Infect", ParameterKind::None),
+    keyword("Ingest", ParameterKind::None),
+    keyword("Intimidate", ParameterKind::None),
+    keyword("Islandwalk", ParameterKind::None),
+    keyword("Jump-start", ParameterKind::None),
+    keyword("Kicker", ParameterKind::Cost),
+    keyword("Level up", ParameterKind::Cost),
+    keyword("Lifelink", ParameterKind::None),
+    keyword("Living weapon", ParameterKind::None),
+    keyword("Madness", ParameterKind::Cost),
+    keyword("Megamorph", ParameterKind::Cost),
+    keyword("Melee", ParameterKind::None),
+    keyword("Menace", ParameterKind::None),
+    keyword("Mentor", ParameterKind::None),
+    keyword("Miracle", ParameterKind::Cost),
+    keyword("Modular", ParameterKind::Number),
+    keyword("Morph", ParameterKind::Cost),
+    keyword("Mountainwalk", ParameterKind::None),
+    keyword("Multikicker", ParameterKind::Cost),
+    keyword("Mutate", ParameterKind::Cost),
+    keyword("Myriad", ParameterKind::None),
+    keyword("Nightbound", ParameterKind::None),
+    keyword("Ninjutsu", ParameterKind::Cost),
+    keyword("Outlast", ParameterKind::Cost),
+    keyword("Overload", ParameterKind::Cost),
+    keyword("Partner", ParameterKind::None),
+    keyword("Persist", ParameterKind::None),
+    keyword("Plainswalk", ParameterKind::None),
+    keyword("Plot", ParameterKind::Cost),
+    keyword("Poisonous", ParameterKind::Number),
+    keyword("Protection", ParameterKind::Quality("from")),
+    keyword("Prowess", ParameterKind::None),
+    keyword("Rampage", ParameterKind::Number),
+    keyword("Reach", ParameterKind::None),
+    keyword("Read ahead", ParameterKind::None),
+    keyword("Rebound", ParameterKind::None),
+    keyword("Reconfigure", ParameterKind::Cost),
+    keyword("Renown", ParameterKind::Number),
+    keyword("Replicate", ParameterKind::Cost),
+    keyword("Retrace", ParameterKind::None),
+    keyword("Riot", ParameterKind::None),
+    keyword("Ripple", ParameterKind::Number),
+    keyword("Saddle", ParameterKind::Number),
+    keyword("Scavenge", ParameterKind::Cost),
+    keyword("Shadow", ParameterKind::None),
+    keyword("Shroud", ParameterKind::None),
+    keyword("Skulk", ParameterKind::None),
+    keyword("Soulbond", ParameterKind::None),
+    keyword("Soulshift", ParameterKind::Number),
+    keyword("Spectacle", ParameterKind::Cost),
+    keyword("Split second", ParameterKind::None),
+    keyword("Storm", ParameterKind::None),
+    keyword("Sunburst", ParameterKind::None),
+    keyword("Surge", ParameterKind::Cost),
+    keyword("Suspend", ParameterKind::Cost),
+    keyword("Swampwalk", ParameterKind::None),
+    keyword("Toxic", ParameterKind::Number),
+    keyword("Training", ParameterKind::None),
+    keyword("Trample", ParameterKind::None),
+    keyword("Transmute", ParameterKind::Cost),
+    keyword("Tribute", ParameterKind::Number),
+    keyword("Undying", ParameterKind::None),
+    keyword("Unearth", ParameterKind::Cost),
+    keyword("Unleash", ParameterKind::None),
+    keyword("Vanishing", ParameterKind::Number),
+    keyword("Vigilance", ParameterKind::None),
+    keyword("Ward", ParameterKind::Cost),
+    keyword("Wither", ParameterKind::None),
+];
+
+/// The parameter of a keyword ability
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum KeywordParameter {
+    Cost(String),
+    Number(u32),
+    Text(String),
+}
+
+/// A keyword ability found in the text of a card
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct KeywordAbility {
+    /// The name as it appears in the catalog
+    pub name: String,
+    pub parameter: Option<KeywordParameter>,
+}
+
+impl KeywordAbility {
+    /// Parses the mana cost of a cost parameter, if it is a mana cost
+    pub fn mana_cost(&self) -> Option<ManaCost> {
+        match &self.parameter {
+            Some(KeywordParameter::Cost(cost)) => cost.parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn parse(part: &str) -> Option<KeywordAbility> {
+        let part = part.trim().trim_end_matches('.').trim();
+        let definition = KEYWORDS
+            .iter()
+            .filter(|definition| starts_with_ignore_case(part, definition.name))
+            .max_by_key(|definition| definition.name.len())?;
+        let rest = &part[definition.name.len()..];
+        if !rest.is_empty() && !rest.starts_with(|c: char| c.is_whitespace() || c == '—') {
+            return None;
+        }
+        let rest = rest.trim_start_matches('—').trim();
+        let parameter = match (definition.parameter, rest.is_empty()) {
+            (ParameterKind::None, true)
+            | (ParameterKind::OptionalQuality(_), true)
+            | (ParameterKind::Number, true) => None,
+            (ParameterKind::Cost, true) | (ParameterKind::Quality(_), true) => return None,
+            (ParameterKind::None, false) => return None,
+            (ParameterKind::Cost, false) => Some(KeywordParameter::Cost(rest.to_string())),
+            (ParameterKind::Number, false) => match rest.parse::<u32>() {
+                Ok(number) => Some(KeywordParameter::Number(number)),
+                Err(_) if rest.split_whitespace().count() == 1 => {
+                    Some(KeywordParameter::Text(rest.to_string()))
+                }
+                Err(_) => return None,
+            },
+            (ParameterKind::Quality(connector), false)
+            | (ParameterKind::OptionalQuality(connector), false) => {
+                let quality = strip_connector(rest, connector)?;
+                Some(KeywordParameter::Text(quality.to_string()))
+            }
+        };
+        Some(KeywordAbility {
+            name: definition.name.to_string(),
+            parameter,
+        })
+    }
+}
+
+/// Extracts the keyword abilities from the text of a card
+///
+/// Only lines consisting of keyword abilities are considered, so `Creatures without flying
+/// can't block` doesn't count as flying. Costs may contain commas, e.g. `Cycling—{2}, Pay 2 life.`
+/// Reminder text in parentheses is ignored and the name of the card is replaced before the text is read.
+///
+/// ```
+/// # use mtgapi_client::model::keyword::*;
+/// let text = "Flying, lifelink\nWard {2}\nToxic 1 (Players dealt combat damage by this creature also get a poison counter.)\n\
+///     Protection from red\nCreatures without flying can't block.";
+/// let keywords = extract_keywords(text, "Example Angel");
+/// let names: Vec<&str> = keywords.iter().map(|keyword| keyword.name.as_str()).collect();
+/// assert_eq!(names, vec!["Flying", "Lifelink", "Ward", "Toxic", "Protection"]);
+/// assert_eq!(keywords[2].parameter, Some(KeywordParameter::Cost("{2}".to_string())));
+/// assert_eq!(keywords[3].parameter, Some(KeywordParameter::Number(1)));
+/// assert_eq!(keywords[4].parameter, Some(KeywordParameter::Text("red".to_string())));
+/// ```
+pub fn extract_keywords(text: &str, card_name: &str) -> Vec<KeywordAbility> {
+    let text = strip_reminder_text(text);
+    let text = if card_name.is_empty() {
+        text
+    } else {
+        text.replace(card_name, "CARDNAME")
+    };
+    text.lines()
+        .filter_map(parse_keyword_line)
+        .flatten()
+        .collect()
+}
+
+/// Parses a line consisting of keyword abilities, e.g. `Flying, lifelink`
+pub(crate) fn parse_keyword_line(line: &str) -> Option<Vec<KeywordAbility>> {
+    line.split([',', ';'])
+        .map(KeywordAbility::parse)
+        .collect::<Option<Vec<_>>>()
+        .or_else(|| KeywordAbility::parse(line).map(|keyword| vec![keyword]))
+}
+
+/// Returns a predicate matching cards with all the specified keyword abilities
+///
+/// ```
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::model::keyword::with_keywords;
+/// let cards = vec![
+///     CardDetail { name: "A".to_string(), text: "Flying, lifelink".to_string(), ..Default::default() },
+///     CardDetail { name: "B".to_string(), text: "Creatures without flying can't block.".to_string(), ..Default::default() },
+/// ];
+/// let matching: Vec<_> = cards.iter().filter(|card| with_keywords(&["flying", "Lifelink"])(card)).collect();
+/// assert_eq!(matching.len(), 1);
+/// ```
+pub fn with_keywords<'a>(names: &'a [&'a str]) -> impl Fn(&CardDetail) -> bool + 'a {
+    move |card| names.iter().all(|name| card.has_keyword(name))
+}
+
+impl CardDetail {
+    /// Extracts the keyword abilities from the text of the card
+    pub fn keywords(&self) -> Vec<KeywordAbility> {
+        extract_keywords(&self.text, &self.name)
+    }
+
+    /// Returns true if the card has the keyword ability, ignoring the case
+    pub fn has_keyword(&self, name: &str) -> bool {
+        self.keywords()
+            .iter()
+            .any(|keyword| keyword.name.eq_ignore_ascii_case(name))
+    }
+}
+
+pub(crate) fn starts_with_ignore_case(value: &str, prefix: &str) -> bool {
+    value.len() >= prefix.len()
+        && value.is_char_boundary(prefix.len())
+        && value[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn strip_connector<'a>(value: &'a str, connector: &str) -> Option<&'a str> {
+    if connector.is_empty() {
+        return Some(value);
+    }
+    let prefix = format!("{} ", connector);
+    if starts_with_ignore_case(value, &prefix) {
+        Some(value[prefix.len()..].trim())
+    } else {
+        None
+    }
+}
+
+pub(crate) fn strip_reminder_text(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut depth = 0;
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => depth -= 1,
+            _ if depth == 0 => stripped.push(c),
+            _ => (),
+        }
+    }
+    stripped
+}
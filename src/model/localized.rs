@@ -0,0 +1,189 @@
+use crate::api::card::filtertypes::CardLanguage;
+use crate::model::card::{CardDetail, ForeignName};
+use crate::model::ids::MultiverseId;
+use std::collections::HashMap;
+
+impl CardDetail {
+    /// Returns the foreign name entry of the card in the language
+    pub fn foreign_name(&self, language: &CardLanguage) -> Option<&ForeignName> {
+        self.foreign_names
+            .iter()
+            .find(|foreign_name| foreign_name.language == *language)
+    }
+
+    /// Returns the name of the card in the language, if the card was printed in it
+    pub fn name_in(&self, language: CardLanguage) -> Option<&str> {
+        self.foreign_name(&language)
+            .map(|foreign_name| foreign_name.name.as_str())
+    }
+
+    /// Returns the image url of the card printed in the language
+    pub fn image_url_in(&self, language: CardLanguage) -> Option<&str> {
+        self.foreign_name(&language)
+            .and_then(|foreign_name| foreign_name.image_url.as_deref())
+    }
+
+    /// Returns a view of the card in the language, falling back to English
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::{CardDetail, ForeignName};
+    /// let card = CardDetail {
+    ///     name: "Shock".to_string(),
+    ///     image_url: Some("shock.jpg".to_string()),
+    ///     foreign_names: vec![ForeignName {
+    ///         name: "Schock".to_string(),
+    ///         language: CardLanguage::German,
+    ///         ..Default::default()
+    ///     }],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(card.name_in(CardLanguage::German), Some("Schock"));
+    ///
+    /// let german = card.localized(CardLanguage::German);
+    /// assert!(german.is_translated());
+    /// assert_eq!(german.name(), "Schock");
+    /// assert_eq!(german.image_url(), Some("shock.jpg"));
+    /// assert_eq!(card.localized(CardLanguage::French).name(), "Shock");
+    /// ```
+    pub fn localized(&self, language: CardLanguage) -> LocalizedCard<'_> {
+        let foreign_name = self.foreign_name(&language);
+        LocalizedCard {
+            card: self,
+            language,
+            foreign_name,
+        }
+    }
+}
+
+/// A card seen in a language, values not available in the language are taken from the English card
+#[derive(Clone, Debug)]
+pub struct LocalizedCard<'a> {
+    pub card: &'a CardDetail,
+    pub language: CardLanguage,
+    pub foreign_name: Option<&'a ForeignName>,
+}
+
+impl<'a> LocalizedCard<'a> {
+    /// Returns true if the card was printed in the language
+    pub fn is_translated(&self) -> bool {
+        self.foreign_name.is_some()
+    }
+
+    /// Returns the name in the language or the English name
+    pub fn name(&self) -> &'a str {
+        match self.foreign_name {
+            Some(foreign_name) => &foreign_name.name,
+            None => &self.card.name,
+        }
+    }
+
+    /// Returns the image url of the printing in the language or of the English printing
+    pub fn image_url(&self) -> Option<&'a str> {
+        self.foreign_name
+            .and_then(|foreign_name| foreign_name.image_url.as_deref())
+            .or(self.card.image_url.as_deref())
+    }
+
+    /// Returns the multiverse id of the printing in the language or of the English printing
+    pub fn multiverseid(&self) -> Option<MultiverseId> {
+        self.foreign_name
+            .and_then(|foreign_name| foreign_name.multiverseid)
+            .or(self.card.multiverseid)
+    }
+}
+
+/// An index resolving foreign names of cards to the English cards
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::{CardDetail, ForeignName};
+/// # use mtgapi_client::model::localized::ForeignNameIndex;
+/// let card = |name: &str, foreign: &str, language: CardLanguage| CardDetail {
+///     name: name.to_string(),
+///     foreign_names: vec![ForeignName {
+///         name: foreign.to_string(),
+///         language,
+///         ..Default::default()
+///     }],
+///     ..Default::default()
+/// };
+/// let index = ForeignNameIndex::new(vec![
+///     card("Shock", "Schock", CardLanguage::German),
+///     card("Counterspell", "Contresort", CardLanguage::French),
+/// ]);
+/// assert_eq!(index.resolve("schock").unwrap().name, "Shock");
+/// assert_eq!(index.resolve_in("Contresort", &CardLanguage::French).unwrap().name, "Counterspell");
+/// assert!(index.resolve_in("Contresort", &CardLanguage::German).is_none());
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ForeignNameIndex {
+    cards: Vec<CardDetail>,
+    /// The English names with the index of their card
+    positions: HashMap<String, usize>,
+    names: HashMap<String, Vec<(CardLanguage, usize)>>,
+}
+
+impl ForeignNameIndex {
+    /// Indexes the foreign names of the cards, each English card is kept once
+    pub fn new<I>(cards: I) -> ForeignNameIndex
+    where
+        I: IntoIterator<Item = CardDetail>,
+    {
+        let mut index = ForeignNameIndex::default();
+        for card in cards {
+            index.insert(card);
+        }
+        index
+    }
+
+    /// Adds the foreign names of the card to the index
+    pub fn insert(&mut self, card: CardDetail) {
+        let position = match self.positions.get(&card.name) {
+            Some(position) => *position,
+            None => {
+                let position = self.cards.len();
+                self.positions.insert(card.name.clone(), position);
+                self.cards.push(card.clone());
+                position
+            }
+        };
+        for foreign_name in &card.foreign_names {
+            let entries = self.names.entry(index_key(&foreign_name.name)).or_default();
+            let entry = (foreign_name.language.clone(), position);
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    /// Returns the English card with the foreign name in any language, ignoring the case
+    pub fn resolve(&self, name: &str) -> Option<&CardDetail> {
+        self.names
+            .get(&index_key(name))
+            .and_then(|entries| entries.first())
+            .map(|(_, position)| &self.cards[*position])
+    }
+
+    /// Returns the English card with the foreign name in the language, ignoring the case
+    pub fn resolve_in(&self, name: &str, language: &CardLanguage) -> Option<&CardDetail> {
+        self.names
+            .get(&index_key(name))
+            .and_then(|entries| entries.iter().find(|(known, _)| known == language))
+            .map(|(_, position)| &self.cards[*position])
+    }
+
+    /// Returns the number of English cards in the index
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the index holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+}
+
+fn index_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
@@ -0,0 +1,320 @@
+use crate::api::card::filtertypes::{CardColor, CardColorIdentity};
+use crate::api::error::MtgApiErrorKind;
+use failure::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The basic land types with the color of mana they produce
+pub const BASIC_LAND_TYPES: &[(&str, ManaColor)] = &[
+    ("Plains", ManaColor::White),
+    ("Island", ManaColor::Blue),
+    ("Swamp", ManaColor::Black),
+    ("Mountain", ManaColor::Red),
+    ("Forest", ManaColor::Green),
+];
+
+/// The five colors of mana, in the canonical WUBRG order
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ManaColor {
+    White,
+    Blue,
+    Black,
+    Red,
+    Green,
+}
+
+impl ManaColor {
+    /// Returns all colors in WUBRG order
+    pub fn values() -> &'static [ManaColor] {
+        use self::ManaColor::*;
+        &[White, Blue, Black, Red, Green]
+    }
+
+    /// Returns the letter used in mana symbols
+    pub fn symbol(self) -> char {
+        use self::ManaColor::*;
+        match self {
+            White => 'W',
+            Blue => 'U',
+            Black => 'B',
+            Red => 'R',
+            Green => 'G',
+        }
+    }
+
+    /// Returns the color for the letter used in mana symbols
+    pub fn from_symbol(symbol: char) -> Option<ManaColor> {
+        ManaColor::values()
+            .iter()
+            .cloned()
+            .find(|color| color.symbol() == symbol.to_ascii_uppercase())
+    }
+
+    /// Returns the mana color of the card color, unknown colors have none
+    pub fn from_card_color(color: &CardColor) -> Option<ManaColor> {
+        ManaColor::values()
+            .iter()
+            .cloned()
+            .find(|mana_color| CardColor::from(*mana_color) == *color)
+    }
+
+    /// Returns the mana color of the color identity code, unknown codes have none
+    pub fn from_identity(identity: &CardColorIdentity) -> Option<ManaColor> {
+        ManaColor::values()
+            .iter()
+            .cloned()
+            .find(|mana_color| CardColorIdentity::from(*mana_color) == *identity)
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl From<ManaColor> for CardColor {
+    fn from(color: ManaColor) -> CardColor {
+        match color {
+            ManaColor::White => CardColor::White,
+            ManaColor::Blue => CardColor::Blue,
+            ManaColor::Black => CardColor::Black,
+            ManaColor::Red => CardColor::Red,
+            ManaColor::Green => CardColor::Green,
+        }
+    }
+}
+
+impl From<ManaColor> for CardColorIdentity {
+    fn from(color: ManaColor) -> CardColorIdentity {
+        match color {
+            ManaColor::White => CardColorIdentity::W,
+            ManaColor::Blue => CardColorIdentity::U,
+            ManaColor::Black => CardColorIdentity::B,
+            ManaColor::Red => CardColorIdentity::R,
+            ManaColor::Green => CardColorIdentity::G,
+        }
+    }
+}
+
+/// A single mana symbol of a mana cost
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ManaSymbol {
+    /// Generic mana, e.g. `{2}`
+    Generic(u32),
+    /// Colored mana, e.g. `{W}`
+    Colored(ManaColor),
+    /// Colorless mana `{C}`
+    Colorless,
+    /// Hybrid mana of two colors, e.g. `{W/U}`
+    Hybrid(ManaColor, ManaColor),
+    /// Two generic or one colored mana, e.g. `{2/W}`
+    TwoBrid(ManaColor),
+    /// Phyrexian mana, payable with colored mana or 2 life, e.g. `{U/P}`
+    Phyrexian(ManaColor),
+    /// Phyrexian hybrid mana, e.g. `{G/W/P}`
+    PhyrexianHybrid(ManaColor, ManaColor),
+    /// Snow mana `{S}`
+    Snow,
+    /// Variable mana `{X}`, `{Y}` or `{Z}`
+    Variable(char),
+    /// Half mana `{½}`, or half colored mana like `{HW}`
+    Half(Option<ManaColor>),
+}
+
+impl ManaSymbol {
+    /// Returns the contribution of the symbol to the mana value
+    pub fn mana_value(&self) -> f64 {
+        use self::ManaSymbol::*;
+        match self {
+            Generic(amount) => f64::from(*amount),
+            TwoBrid(_) => 2.0,
+            Variable(_) => 0.0,
+            Half(_) => 0.5,
+            Colored(_) | Colorless | Hybrid(..) | Phyrexian(_) | PhyrexianHybrid(..) | Snow => 1.0,
+        }
+    }
+
+    /// Returns the colors of the symbol
+    pub fn colors(&self) -> Vec<ManaColor> {
+        use self::ManaSymbol::*;
+        match *self {
+            Colored(color) | TwoBrid(color) | Phyrexian(color) | Half(Some(color)) => vec![color],
+            Hybrid(first, second) | PhyrexianHybrid(first, second) => vec![first, second],
+            Generic(_) | Colorless | Snow | Variable(_) | Half(None) => Vec::new(),
+        }
+    }
+
+    /// Returns true if the symbol is of the specified color
+    pub fn has_color(&self, color: ManaColor) -> bool {
+        self.colors().contains(&color)
+    }
+
+    fn parse(symbol: &str) -> Option<ManaSymbol> {
+        use self::ManaSymbol::*;
+        let symbol = symbol.trim().to_uppercase();
+        if let Ok(amount) = symbol.parse::<u32>() {
+            return Some(Generic(amount));
+        }
+        let parts: Vec<&str> = symbol.split('/').collect();
+        let color = |part: &str| {
+            let mut chars = part.chars();
+            match (chars.next(), chars.next()) {
+                (Some(symbol), None) => ManaColor::from_symbol(symbol),
+                _ => None,
+            }
+        };
+        match parts.as_slice() {
+            ["C"] => Some(Colorless),
+            ["S"] => Some(Snow),
+            ["X"] => Some(Variable('X')),
+            ["Y"] => Some(Variable('Y')),
+            ["Z"] => Some(Variable('Z')),
+            ["½"] | ["H"] => Some(Half(None)),
+            [single] if single.starts_with('H') && single.chars().count() == 2 => {
+                color(&single[1..]).map(|color| Half(Some(color)))
+            }
+            ["H", half] => color(half).map(|color| Half(Some(color))),
+            [single] => color(single).map(Colored),
+            ["2", second] => color(second).map(TwoBrid),
+            [first, "P"] => color(first).map(Phyrexian),
+            [first, second] => match (color(first), color(second)) {
+                (Some(first), Some(second)) if first != second => {
+                    let (first, second) = canonical_pair(first, second);
+                    Some(Hybrid(first, second))
+                }
+                _ => None,
+            },
+            [first, second, "P"] => match (color(first), color(second)) {
+                (Some(first), Some(second)) if first != second => {
+                    let (first, second) = canonical_pair(first, second);
+                    Some(PhyrexianHybrid(first, second))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Orders a hybrid pair the way it is printed, e.g. `W/U`, `G/W` or `R/W`
+fn canonical_pair(first: ManaColor, second: ManaColor) -> (ManaColor, ManaColor) {
+    let distance = (second.index() + 5 - first.index()) % 5;
+    if distance <= 2 {
+        (first, second)
+    } else {
+        (second, first)
+    }
+}
+
+impl Display for ManaSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ManaSymbol::*;
+        match *self {
+            Generic(amount) => write!(f, "{{{}}}", amount),
+            Colored(color) => write!(f, "{{{}}}", color.symbol()),
+            Colorless => write!(f, "{{C}}"),
+            Hybrid(first, second) => write!(f, "{{{}/{}}}", first.symbol(), second.symbol()),
+            TwoBrid(color) => write!(f, "{{2/{}}}", color.symbol()),
+            Phyrexian(color) => write!(f, "{{{}/P}}", color.symbol()),
+            PhyrexianHybrid(first, second) => {
+                write!(f, "{{{}/{}/P}}", first.symbol(), second.symbol())
+            }
+            Snow => write!(f, "{{S}}"),
+            Variable(variable) => write!(f, "{{{}}}", variable),
+            Half(None) => write!(f, "{{½}}"),
+            Half(Some(color)) => write!(f, "{{H{}}}", color.symbol()),
+        }
+    }
+}
+
+/// A parsed mana cost like `{2}{W}{U/P}{X}`
+///
+/// ```
+/// # use mtgapi_client::model::mana::*;
+/// let cost: ManaCost = "{2}{W}{U/P}{X}".parse().unwrap();
+/// assert_eq!(cost.mana_value(), 4.0);
+/// assert_eq!(cost.colors(), vec![ManaColor::White, ManaColor::Blue]);
+/// assert_eq!(cost.devotion(ManaColor::Blue), 1);
+/// assert_eq!(cost.to_string(), "{2}{W}{U/P}{X}");
+///
+/// let cost: ManaCost = "{u/w}{2/G}{½}".parse().unwrap();
+/// assert_eq!(cost.to_string(), "{W/U}{2/G}{½}");
+/// assert_eq!(cost.mana_value(), 3.5);
+///
+/// assert!("{2}{Q}".parse::<ManaCost>().is_err());
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Default, Debug)]
+pub struct ManaCost {
+    pub symbols: Vec<ManaSymbol>,
+}
+
+impl ManaCost {
+    /// Returns true if the cost has no symbols
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the mana value (converted mana cost), variable mana counts as zero
+    pub fn mana_value(&self) -> f64 {
+        self.symbols.iter().map(ManaSymbol::mana_value).sum()
+    }
+
+    /// Returns the colors of the cost in WUBRG order
+    pub fn colors(&self) -> Vec<ManaColor> {
+        ManaColor::values()
+            .iter()
+            .cloned()
+            .filter(|color| self.symbols.iter().any(|symbol| symbol.has_color(*color)))
+            .collect()
+    }
+
+    /// Returns the number of symbols of the specified color, hybrid symbols count for both colors
+    pub fn devotion(&self, color: ManaColor) -> u32 {
+        self.devotion_to(&[color])
+    }
+
+    /// Returns the number of symbols of any of the specified colors
+    ///
+    /// ```
+    /// # use mtgapi_client::model::mana::*;
+    /// let cost: ManaCost = "{W}{W/U}{U}{1}".parse().unwrap();
+    /// assert_eq!(cost.devotion(ManaColor::White), 2);
+    /// assert_eq!(cost.devotion_to(&[ManaColor::White, ManaColor::Blue]), 3);
+    /// ```
+    pub fn devotion_to(&self, colors: &[ManaColor]) -> u32 {
+        self.symbols
+            .iter()
+            .filter(|symbol| colors.iter().any(|color| symbol.has_color(*color)))
+            .count() as u32
+    }
+}
+
+impl FromStr for ManaCost {
+    type Err = Error;
+
+    fn from_str(cost: &str) -> Result<ManaCost, Error> {
+        let invalid = || MtgApiErrorKind::InvalidManaCost {
+            cost: cost.to_string(),
+        };
+        let mut symbols = Vec::new();
+        let mut rest = cost.trim();
+        while !rest.is_empty() {
+            if !rest.starts_with('{') {
+                return Err(invalid().into());
+            }
+            let end = rest.find('}').ok_or_else(invalid)?;
+            symbols.push(ManaSymbol::parse(&rest[1..end]).ok_or_else(invalid)?);
+            rest = rest[end + 1..].trim_start();
+        }
+        Ok(ManaCost { symbols })
+    }
+}
+
+impl Display for ManaCost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for symbol in &self.symbols {
+            write!(f, "{}", symbol)?;
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,19 @@
+pub mod ability;
+pub mod booster;
 pub mod card;
+pub mod collector;
 pub mod format;
+pub mod identity;
+pub mod ids;
+pub mod keyword;
+pub mod localized;
+pub mod mana;
+pub mod multiface;
+pub mod oracle;
+pub mod payment;
+pub mod production;
+pub mod resolver;
 pub mod set;
+pub mod stat;
+pub mod typeline;
 pub mod types;
@@ -0,0 +1,193 @@
+use crate::api::card::filtertypes::CardLayout;
+use crate::model::card::CardDetail;
+use crate::model::collector::CollectorNumber;
+use crate::model::ids::SetCode;
+use std::collections::HashMap;
+
+const NAME_SEPARATOR: &str = " // ";
+
+/// A logical card made of all its faces, e.g. both halves of a split card
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::model::multiface::MultiFaceCard;
+/// let face = |name: &str, number: &str, mana_cost: &str| CardDetail {
+///     name: name.to_string(),
+///     names: vec!["Fire".to_string(), "Ice".to_string()],
+///     number: Some(number.into()),
+///     mana_cost: Some(mana_cost.to_string()),
+///     layout: Some(CardLayout::Split),
+///     set: "MH2".into(),
+///     ..Default::default()
+/// };
+/// let cards = vec![
+///     face("Ice", "290b", "{1}{U}"),
+///     CardDetail { name: "Shock".to_string(), set: "MH2".into(), ..Default::default() },
+///     face("Fire", "290a", "{1}{R}"),
+/// ];
+/// let grouped = MultiFaceCard::group(cards);
+/// assert_eq!(grouped.len(), 2);
+/// assert_eq!(grouped[0].name(), "Fire // Ice");
+/// assert_eq!(grouped[0].mana_value(), 4.0);
+/// assert!(grouped[0].is_multi_face());
+/// assert_eq!(grouped[1].name(), "Shock");
+/// assert!(MultiFaceCard::from_faces(Vec::new()).is_none());
+/// ```
+#[derive(Clone, Debug)]
+pub struct MultiFaceCard {
+    pub layout: CardLayout,
+    /// The faces in the order of the `names` of the card, never empty
+    faces: Vec<CardDetail>,
+}
+
+impl MultiFaceCard {
+    /// Creates a card with a single face
+    pub fn single(card: CardDetail) -> MultiFaceCard {
+        MultiFaceCard {
+            layout: card.layout.clone().unwrap_or(CardLayout::Normal),
+            faces: vec![card],
+        }
+    }
+
+    /// Creates a card from its faces, which are ordered by the `names` of the faces
+    ///
+    /// Returns `None` if there are no faces.
+    pub fn from_faces(mut faces: Vec<CardDetail>) -> Option<MultiFaceCard> {
+        if faces.is_empty() {
+            return None;
+        }
+        let names: Vec<String> = faces
+            .iter()
+            .find(|face| !face.names.is_empty())
+            .map(|face| face.names.clone())
+            .unwrap_or_default();
+        faces.sort_by_key(|face| {
+            let position = names.iter().position(|name| *name == face.name);
+            (position.unwrap_or(usize::MAX), face.number.clone())
+        });
+        let layout = faces
+            .iter()
+            .find_map(|face| face.layout.clone())
+            .unwrap_or(CardLayout::Normal);
+        Some(MultiFaceCard { layout, faces })
+    }
+
+    /// Groups the cards into logical cards, faces are linked through their `names`
+    ///
+    /// Faces are only grouped within the same set and collector number, except for meld cards,
+    /// whose front faces have numbers of their own. The order of the first face is kept.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::multiface::MultiFaceCard;
+    /// let names = ["Bruna, the Fading Light", "Brisela, Voice of Nightmares", "Gisela, the Broken Blade"];
+    /// let face = |name: &str, number: &str| CardDetail {
+    ///     name: name.to_string(),
+    ///     names: names.iter().map(|name| name.to_string()).collect(),
+    ///     number: Some(number.into()),
+    ///     layout: Some(CardLayout::Meld),
+    ///     set: "EMN".into(),
+    ///     ..Default::default()
+    /// };
+    /// let grouped = MultiFaceCard::group(vec![
+    ///     face(names[2], "28a"),
+    ///     face(names[0], "15a"),
+    ///     face(names[1], "15b"),
+    /// ]);
+    /// assert_eq!(grouped.len(), 1);
+    /// assert_eq!(grouped[0].faces().len(), 3);
+    /// assert_eq!(grouped[0].name(), names[0]);
+    /// ```
+    pub fn group<I>(cards: I) -> Vec<MultiFaceCard>
+    where
+        I: IntoIterator<Item = CardDetail>,
+    {
+        let mut groups: Vec<Vec<CardDetail>> = Vec::new();
+        let mut index: HashMap<(SetCode, Vec<String>, Option<CollectorNumber>), usize> =
+            HashMap::new();
+        for card in cards {
+            if card.names.len() <= 1 {
+                groups.push(vec![card]);
+                continue;
+            }
+            let mut names = card.names.clone();
+            names.sort();
+            let number = match card.layout {
+                Some(CardLayout::Meld) => None,
+                _ => card.number.as_ref().map(CollectorNumber::base),
+            };
+            let key = (card.set.clone(), names, number);
+            match index.get(&key) {
+                Some(group) if !groups[*group].iter().any(|face| face.name == card.name) => {
+                    groups[*group].push(card)
+                }
+                _ => {
+                    index.insert(key, groups.len());
+                    groups.push(vec![card]);
+                }
+            }
+        }
+        groups
+            .into_iter()
+            .filter_map(MultiFaceCard::from_faces)
+            .collect()
+    }
+
+    /// Returns the faces in the order of the `names` of the card, the front face first
+    pub fn faces(&self) -> &[CardDetail] {
+        &self.faces
+    }
+
+    /// Returns the front face
+    pub fn front(&self) -> &CardDetail {
+        &self.faces[0]
+    }
+
+    /// Returns true if the card has more than one face
+    pub fn is_multi_face(&self) -> bool {
+        self.faces.len() > 1
+    }
+
+    /// Returns true if all faces listed in `names` are present
+    pub fn is_complete(&self) -> bool {
+        self.front()
+            .names
+            .iter()
+            .all(|name| self.faces.iter().any(|face| face.name == *name))
+    }
+
+    /// Returns the name of the card
+    ///
+    /// Split, adventure, flip and double faced cards show all faces, e.g. `Fire // Ice`.
+    /// Meld cards show only the front face, the melded card is a different card.
+    pub fn name(&self) -> String {
+        match self.layout {
+            CardLayout::Meld => self.front().name.clone(),
+            _ => self
+                .faces
+                .iter()
+                .map(|face| face.name.as_str())
+                .collect::<Vec<_>>()
+                .join(NAME_SEPARATOR),
+        }
+    }
+
+    /// Returns the mana value of the card outside of the stack
+    ///
+    /// Split cards combine the mana values of their halves, all other cards use their front face.
+    pub fn mana_value(&self) -> f64 {
+        match self.layout {
+            CardLayout::Split => self.faces.iter().map(face_mana_value).sum(),
+            _ => face_mana_value(self.front()),
+        }
+    }
+}
+
+fn face_mana_value(face: &CardDetail) -> f64 {
+    match face.parsed_mana_cost() {
+        Ok(Some(cost)) => cost.mana_value(),
+        _ => face.cmc,
+    }
+}
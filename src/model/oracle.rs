@@ -0,0 +1,176 @@
+use crate::api::card::filtertypes::{CardColor, CardColorIdentity, CardLayout, CardRarity};
+use crate::model::card::{CardDetail, Legality, Ruling};
+use crate::model::collector::CollectorNumber;
+use crate::model::ids::{CardId, MultiverseId, SetCode};
+use crate::model::stat::StatValue;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+/// The identity of an oracle card: its name and the names of all its faces
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct OracleKey {
+    pub name: String,
+    pub names: Vec<String>,
+}
+
+impl CardDetail {
+    /// Returns the oracle identity of the card, shared by all its printings
+    pub fn oracle_key(&self) -> OracleKey {
+        OracleKey {
+            name: self.name.clone(),
+            names: self.names.clone(),
+        }
+    }
+}
+
+/// A single printing of a card
+#[derive(Clone, Debug)]
+pub struct Printing {
+    pub id: CardId,
+    pub set: SetCode,
+    pub set_name: Option<String>,
+    pub number: Option<CollectorNumber>,
+    pub rarity: CardRarity,
+    pub artist: String,
+    pub multiverseid: Option<MultiverseId>,
+    pub image_url: Option<String>,
+    pub border: Option<String>,
+    pub flavor: String,
+    pub watermark: Option<String>,
+    pub release_date: Option<NaiveDate>,
+}
+
+impl From<&CardDetail> for Printing {
+    fn from(card: &CardDetail) -> Printing {
+        Printing {
+            id: card.id.clone(),
+            set: card.set.clone(),
+            set_name: card.set_name.clone(),
+            number: card.number.clone(),
+            rarity: card.rarity.clone(),
+            artist: card.artist.clone(),
+            multiverseid: card.multiverseid,
+            image_url: card.image_url.clone(),
+            border: card.border.clone(),
+            flavor: card.flavor.clone(),
+            watermark: card.watermark.clone(),
+            release_date: card.release_date,
+        }
+    }
+}
+
+/// A card with the gameplay fields shared by all printings and the list of its printings
+///
+/// ```
+/// # use mtgapi_client::model::card::CardDetail;
+/// # use mtgapi_client::model::oracle::OracleCard;
+/// let printing = |id: &str, set: &str| CardDetail {
+///     id: id.into(),
+///     name: "Lightning Bolt".to_string(),
+///     text: "Lightning Bolt deals 3 damage to any target.".to_string(),
+///     set: set.into(),
+///     ..Default::default()
+/// };
+/// let cards = vec![
+///     printing("1", "LEA"),
+///     printing("2", "M10"),
+///     CardDetail { name: "Shock".to_string(), ..Default::default() },
+///     printing("3", "A25"),
+/// ];
+/// let oracle_cards = OracleCard::group(cards);
+/// assert_eq!(oracle_cards.len(), 2);
+/// assert_eq!(oracle_cards[0].name, "Lightning Bolt");
+/// let sets: Vec<&str> = oracle_cards[0].printings.iter().map(|printing| printing.set.as_str()).collect();
+/// assert_eq!(sets, vec!["LEA", "M10", "A25"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct OracleCard {
+    pub name: String,
+    pub names: Vec<String>,
+    pub mana_cost: Option<String>,
+    pub cmc: f64,
+    pub colors: Vec<CardColor>,
+    pub color_identity: Vec<CardColorIdentity>,
+    pub type_: String,
+    pub supertypes: Vec<String>,
+    pub types: Vec<String>,
+    pub subtypes: Vec<String>,
+    pub text: String,
+    pub power: Option<StatValue>,
+    pub toughness: Option<StatValue>,
+    pub loyalty: Option<StatValue>,
+    pub defense: Option<StatValue>,
+    pub layout: Option<CardLayout>,
+    pub rulings: Vec<Ruling>,
+    pub legalities: Vec<Legality>,
+    pub printings: Vec<Printing>,
+}
+
+impl OracleCard {
+    /// Creates an oracle card from a printing
+    pub fn from_printing(card: &CardDetail) -> OracleCard {
+        OracleCard {
+            name: card.name.clone(),
+            names: card.names.clone(),
+            mana_cost: card.mana_cost.clone(),
+            cmc: card.cmc,
+            colors: card.colors.clone(),
+            color_identity: card.color_identity.clone(),
+            type_: card.type_.clone(),
+            supertypes: card.supertypes.clone(),
+            types: card.types.clone(),
+            subtypes: card.subtypes.clone(),
+            text: card.text.clone(),
+            power: card.power.clone(),
+            toughness: card.toughness.clone(),
+            loyalty: card.loyalty.clone(),
+            defense: card.defense.clone(),
+            layout: card.layout.clone(),
+            rulings: card.rulings.clone(),
+            legalities: card.legalities.clone(),
+            printings: vec![Printing::from(card)],
+        }
+    }
+
+    /// Returns the oracle identity of the card
+    pub fn key(&self) -> OracleKey {
+        OracleKey {
+            name: self.name.clone(),
+            names: self.names.clone(),
+        }
+    }
+
+    /// Adds a printing of the same card
+    ///
+    /// Rulings and legalities missing so far are taken from the printing.
+    pub fn add_printing(&mut self, card: &CardDetail) {
+        if self.rulings.is_empty() {
+            self.rulings = card.rulings.clone();
+        }
+        if self.legalities.is_empty() {
+            self.legalities = card.legalities.clone();
+        }
+        if !self.printings.iter().any(|printing| printing.id == card.id) {
+            self.printings.push(Printing::from(card));
+        }
+    }
+
+    /// Groups the printings into oracle cards, in the order of their first printing
+    pub fn group<I>(cards: I) -> Vec<OracleCard>
+    where
+        I: IntoIterator<Item = CardDetail>,
+    {
+        let mut oracle_cards: Vec<OracleCard> = Vec::new();
+        let mut index: HashMap<OracleKey, usize> = HashMap::new();
+        for card in cards {
+            match index.get(&card.oracle_key()) {
+                Some(position) => oracle_cards[*position].add_printing(&card),
+                None => {
+                    index.insert(card.oracle_key(), oracle_cards.len());
+                    oracle_cards.push(OracleCard::from_printing(&card));
+                }
+            }
+        }
+        oracle_cards
+    }
+}
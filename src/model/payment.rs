@@ -0,0 +1,412 @@
+use crate::api::error::MtgApiErrorKind;
+use crate::model::mana::{ManaColor, ManaCost, ManaSymbol};
+use failure::Error;
+use std::str::FromStr;
+
+/// Life paid instead of a single Phyrexian symbol
+pub const PHYREXIAN_LIFE: u32 = 2;
+
+/// A single unit of mana
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ManaType {
+    Colored(ManaColor),
+    Colorless,
+}
+
+/// Something that produces one unit of mana of one of several types, e.g. a land
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ManaSource {
+    pub produces: Vec<ManaType>,
+    pub snow: bool,
+}
+
+impl ManaSource {
+    /// Creates a source producing one of the specified types
+    pub fn new(produces: &[ManaType]) -> ManaSource {
+        ManaSource {
+            produces: produces.to_vec(),
+            snow: false,
+        }
+    }
+
+    /// Creates a source producing one mana of any color
+    pub fn any_color() -> ManaSource {
+        let colors: Vec<ManaType> = ManaColor::values()
+            .iter()
+            .map(|color| ManaType::Colored(*color))
+            .collect();
+        ManaSource::new(&colors)
+    }
+
+    /// Marks the source as snow source, which can pay for `{S}`
+    pub fn snow(mut self) -> ManaSource {
+        self.snow = true;
+        self
+    }
+}
+
+/// A pool of mana that is already available
+///
+/// ```
+/// # use mtgapi_client::model::payment::*;
+/// # use mtgapi_client::model::mana::ManaColor;
+/// let pool: ManaPool = "WWUC".parse().unwrap();
+/// assert_eq!(pool.count(ManaType::Colored(ManaColor::White)), 2);
+/// assert_eq!(pool.len(), 4);
+/// ```
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct ManaPool {
+    mana: Vec<ManaType>,
+}
+
+impl ManaPool {
+    /// Creates an empty pool
+    pub fn new() -> ManaPool {
+        ManaPool::default()
+    }
+
+    /// Adds the amount of mana of the specified type
+    pub fn add(mut self, mana: ManaType, amount: u32) -> ManaPool {
+        for _ in 0..amount {
+            self.mana.push(mana);
+        }
+        self
+    }
+
+    /// Returns the amount of mana of the specified type
+    pub fn count(&self, mana: ManaType) -> usize {
+        self.mana.iter().filter(|entry| **entry == mana).count()
+    }
+
+    /// Returns the total amount of mana
+    pub fn len(&self) -> usize {
+        self.mana.len()
+    }
+
+    /// Returns true if the pool is empty
+    pub fn is_empty(&self) -> bool {
+        self.mana.is_empty()
+    }
+
+    /// Returns every unit of mana in the pool as a source producing exactly that mana
+    pub fn sources(&self) -> Vec<ManaSource> {
+        self.mana
+            .iter()
+            .map(|mana| ManaSource::new(&[*mana]))
+            .collect()
+    }
+}
+
+impl FromStr for ManaPool {
+    type Err = Error;
+
+    /// Parses a pool from mana letters like `WWUC`, braces are ignored
+    fn from_str(pool: &str) -> Result<ManaPool, Error> {
+        let mut mana = Vec::new();
+        for symbol in pool.chars().filter(|symbol| !"{} ".contains(*symbol)) {
+            if symbol.eq_ignore_ascii_case(&'C') {
+                mana.push(ManaType::Colorless);
+            } else {
+                let color = ManaColor::from_symbol(symbol).ok_or_else(|| {
+                    MtgApiErrorKind::UnknownValue {
+                        value: symbol.to_string(),
+                    }
+                })?;
+                mana.push(ManaType::Colored(color));
+            }
+        }
+        Ok(ManaPool { mana })
+    }
+}
+
+/// Options for paying a mana cost
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub struct PaymentOptions {
+    /// The value chosen for `{X}`, `{Y}` and `{Z}`
+    pub x: u32,
+    /// The maximum life that may be paid for Phyrexian symbols, `None` for no limit
+    pub max_life: Option<u32>,
+}
+
+/// How a single symbol of the cost is paid
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PaymentStep {
+    pub symbol: ManaSymbol,
+    /// The indices of the sources used, with the mana they produced
+    pub sources: Vec<(usize, ManaType)>,
+    pub life: u32,
+}
+
+/// A concrete payment of a mana cost
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct Payment {
+    pub steps: Vec<PaymentStep>,
+    /// The total life paid for Phyrexian symbols
+    pub life: u32,
+}
+
+impl Payment {
+    /// Returns the indices of all sources used by the payment
+    pub fn used_sources(&self) -> Vec<usize> {
+        let mut used: Vec<usize> = self
+            .steps
+            .iter()
+            .flat_map(|step| step.sources.iter().map(|(index, _)| *index))
+            .collect();
+        used.sort_unstable();
+        used
+    }
+}
+
+/// What a single unit of mana has to be
+#[derive(Copy, Clone, Debug)]
+enum Requirement {
+    Generic,
+    Color(ManaColor),
+    EitherColor(ManaColor, ManaColor),
+    Colorless,
+    Snow,
+}
+
+impl Requirement {
+    fn accepts(self, mana: ManaType) -> bool {
+        match (self, mana) {
+            (Requirement::Generic, _) | (Requirement::Snow, _) => true,
+            (Requirement::Color(color), ManaType::Colored(produced)) => color == produced,
+            (Requirement::EitherColor(first, second), ManaType::Colored(produced)) => {
+                first == produced || second == produced
+            }
+            (Requirement::Colorless, ManaType::Colorless) => true,
+            _ => false,
+        }
+    }
+
+    fn mana(self, source: &ManaSource) -> Option<ManaType> {
+        if let Requirement::Snow = self {
+            if !source.snow {
+                return None;
+            }
+        }
+        source
+            .produces
+            .iter()
+            .cloned()
+            .find(|mana| self.accepts(*mana))
+    }
+}
+
+/// The ways a single symbol can be paid: units of mana plus life
+type Alternative = (Vec<Requirement>, u32);
+
+fn alternatives(symbol: ManaSymbol, x: u32) -> Vec<Alternative> {
+    use self::ManaSymbol::*;
+    let generic = |amount: u32| vec![Requirement::Generic; amount as usize];
+    match symbol {
+        Generic(amount) => vec![(generic(amount), 0)],
+        Colored(color) | Half(Some(color)) => vec![(vec![Requirement::Color(color)], 0)],
+        Colorless => vec![(vec![Requirement::Colorless], 0)],
+        Hybrid(first, second) => vec![(vec![Requirement::EitherColor(first, second)], 0)],
+        TwoBrid(color) => vec![(vec![Requirement::Color(color)], 0), (generic(2), 0)],
+        Phyrexian(color) => vec![
+            (vec![Requirement::Color(color)], 0),
+            (Vec::new(), PHYREXIAN_LIFE),
+        ],
+        PhyrexianHybrid(first, second) => vec![
+            (vec![Requirement::EitherColor(first, second)], 0),
+            (Vec::new(), PHYREXIAN_LIFE),
+        ],
+        Snow => vec![(vec![Requirement::Snow], 0)],
+        Variable(_) => vec![(generic(x), 0)],
+        Half(None) => vec![(generic(1), 0)],
+    }
+}
+
+impl ManaCost {
+    /// Searches a payment of the cost with the mana sources, each source produces one mana
+    ///
+    /// Payments paying less life are preferred. Half mana symbols need a full unit of mana.
+    ///
+    /// Every combination of the ways to pay twobrid and Phyrexian symbols is tried, identical
+    /// symbols only once per number of them paid each way. The search is still exponential in
+    /// the number of different such symbols, which are few in printed costs.
+    ///
+    /// ```
+    /// # use mtgapi_client::model::mana::*;
+    /// # use mtgapi_client::model::payment::*;
+    /// let cost: ManaCost = "{1}{W}{U/P}".parse().unwrap();
+    /// let lands = vec![
+    ///     ManaSource::new(&[ManaType::Colored(ManaColor::White), ManaType::Colored(ManaColor::Blue)]),
+    ///     ManaSource::new(&[ManaType::Colorless]),
+    /// ];
+    /// let payment = cost.pay(&lands, PaymentOptions::default()).unwrap();
+    /// assert_eq!(payment.life, 2);
+    ///
+    /// let options = PaymentOptions { max_life: Some(0), ..Default::default() };
+    /// assert!(cost.pay(&lands, options).is_none());
+    /// ```
+    pub fn pay(&self, sources: &[ManaSource], options: PaymentOptions) -> Option<Payment> {
+        if self.min_units(options.x) > sources.len() as u64 {
+            return None;
+        }
+        let choices: Vec<Vec<Alternative>> = self
+            .symbols
+            .iter()
+            .map(|symbol| alternatives(*symbol, options.x))
+            .collect();
+        let mut combinations = combinations(&self.symbols, &choices);
+        combinations.sort_by_key(|combination| {
+            let life: u32 = combination.iter().map(|(_, life)| life).sum();
+            let mana: usize = combination.iter().map(|(units, _)| units.len()).sum();
+            (life, mana)
+        });
+        combinations
+            .into_iter()
+            .filter(|combination| {
+                let life: u32 = combination.iter().map(|(_, life)| life).sum();
+                match options.max_life {
+                    Some(max_life) => life <= max_life,
+                    None => true,
+                }
+            })
+            .find_map(|combination| self.assign(&combination, sources))
+    }
+
+    /// Searches a payment of the cost with the mana in the pool
+    ///
+    /// ```
+    /// # use mtgapi_client::model::mana::*;
+    /// # use mtgapi_client::model::payment::*;
+    /// let cost: ManaCost = "{X}{R}{R}".parse().unwrap();
+    /// let pool: ManaPool = "RRRGG".parse().unwrap();
+    /// let options = PaymentOptions { x: 3, ..Default::default() };
+    /// assert!(cost.pay_from_pool(&pool, options).is_some());
+    /// let options = PaymentOptions { x: 4, ..Default::default() };
+    /// assert!(cost.pay_from_pool(&pool, options).is_none());
+    /// let options = PaymentOptions { x: u32::MAX, ..Default::default() };
+    /// assert!(cost.pay_from_pool(&pool, options).is_none());
+    ///
+    /// let cost: ManaCost = "{G/P}".repeat(24).parse().unwrap();
+    /// let pool: ManaPool = "GGGG".parse().unwrap();
+    /// assert_eq!(cost.pay_from_pool(&pool, PaymentOptions::default()).unwrap().life, 40);
+    /// ```
+    pub fn pay_from_pool(&self, pool: &ManaPool, options: PaymentOptions) -> Option<Payment> {
+        self.pay(&pool.sources(), options)
+    }
+
+    /// Returns true if the cost can be paid with the mana sources
+    pub fn can_pay(&self, sources: &[ManaSource], options: PaymentOptions) -> bool {
+        self.pay(sources, options).is_some()
+    }
+
+    /// The fewest units of mana any payment needs, checked before the units are allocated
+    fn min_units(&self, x: u32) -> u64 {
+        use self::ManaSymbol::*;
+        self.symbols
+            .iter()
+            .map(|symbol| match symbol {
+                Generic(amount) => u64::from(*amount),
+                Variable(_) => u64::from(x),
+                Phyrexian(_) | PhyrexianHybrid(_, _) => 0,
+                _ => 1,
+            })
+            .sum()
+    }
+
+    fn assign(&self, combination: &[&Alternative], sources: &[ManaSource]) -> Option<Payment> {
+        let units: Vec<(usize, Requirement)> = combination
+            .iter()
+            .enumerate()
+            .flat_map(|(step, (units, _))| units.iter().map(move |unit| (step, *unit)))
+            .collect();
+        if units.len() > sources.len() {
+            return None;
+        }
+        let mut matched: Vec<Option<usize>> = vec![None; sources.len()];
+        for unit in 0..units.len() {
+            let mut visited = vec![false; sources.len()];
+            if !augment(unit, &units, sources, &mut matched, &mut visited) {
+                return None;
+            }
+        }
+        let mut steps: Vec<PaymentStep> = self
+            .symbols
+            .iter()
+            .zip(combination)
+            .map(|(symbol, (_, life))| PaymentStep {
+                symbol: *symbol,
+                sources: Vec::new(),
+                life: *life,
+            })
+            .collect();
+        for (source, unit) in matched.iter().enumerate() {
+            if let Some(unit) = unit {
+                let (step, requirement) = units[*unit];
+                let mana = requirement.mana(&sources[source])?;
+                steps[step].sources.push((source, mana));
+            }
+        }
+        let life = steps.iter().map(|step| step.life).sum();
+        Some(Payment { steps, life })
+    }
+}
+
+/// Tries to find a source for the unit, moving already matched units if necessary
+fn augment(
+    unit: usize,
+    units: &[(usize, Requirement)],
+    sources: &[ManaSource],
+    matched: &mut Vec<Option<usize>>,
+    visited: &mut Vec<bool>,
+) -> bool {
+    for source in 0..sources.len() {
+        if visited[source] || units[unit].1.mana(&sources[source]).is_none() {
+            continue;
+        }
+        visited[source] = true;
+        let free = match matched[source] {
+            None => true,
+            Some(other) => augment(other, units, sources, matched, visited),
+        };
+        if free {
+            matched[source] = Some(unit);
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the combinations of one alternative per symbol
+///
+/// Identical symbols are interchangeable, so their alternatives are only chosen in order.
+fn combinations<'a>(
+    symbols: &[ManaSymbol],
+    choices: &'a [Vec<Alternative>],
+) -> Vec<Vec<&'a Alternative>> {
+    let mut combinations: Vec<Vec<usize>> = vec![Vec::new()];
+    for (position, alternatives) in choices.iter().enumerate() {
+        let previous = symbols[..position]
+            .iter()
+            .rposition(|symbol| *symbol == symbols[position]);
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                let first = previous.map_or(0, |previous| combination[previous]);
+                (first..alternatives.len()).map(move |index| {
+                    let mut combination = combination.clone();
+                    combination.push(index);
+                    combination
+                })
+            })
+            .collect();
+    }
+    combinations
+        .into_iter()
+        .map(|combination| {
+            combination
+                .iter()
+                .zip(choices)
+                .map(|(index, alternatives)| &alternatives[*index])
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,292 @@
+use crate::model::ability::{Ability, AbilityCost};
+use crate::model::card::CardDetail;
+use crate::model::keyword::starts_with_ignore_case;
+use crate::model::mana::{ManaColor, ManaCost, ManaSymbol, BASIC_LAND_TYPES};
+
+const NUMBERS: &[&str] = &["one", "two", "three", "four", "five"];
+
+/// Where the mana comes from
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ProductionSource {
+    /// An ability printed on the card, e.g. `{T}: Add {G}.`
+    Ability,
+    /// The intrinsic ability of a basic land type
+    BasicLandType,
+    /// The land searched by a fetch land
+    Fetch,
+    /// The effect of an instant or sorcery, e.g. `Add {B}{B}{B}.`
+    Spell,
+}
+
+/// A way the card can produce mana
+#[derive(Clone, PartialEq, Debug)]
+pub struct ManaAbility {
+    /// The colors one of which is produced
+    pub colors: Vec<ManaColor>,
+    /// Colorless mana is produced
+    pub colorless: bool,
+    /// Mana of any color is produced
+    pub any_color: bool,
+    /// The amount of mana produced with a single activation
+    pub amount: u32,
+    pub cost: AbilityCost,
+    pub source: ProductionSource,
+}
+
+impl ManaAbility {
+    /// Returns true if the ability can produce mana of the color
+    pub fn produces(&self, color: ManaColor) -> bool {
+        self.any_color || self.colors.contains(&color)
+    }
+
+    /// Returns true if producing the mana needs the card to be tapped
+    pub fn requires_tap(&self) -> bool {
+        self.cost.tap
+    }
+
+    /// Returns true if producing the mana needs a cost apart from tapping
+    pub fn requires_other_cost(&self) -> bool {
+        self.cost.mana.is_some()
+            || self.cost.untap
+            || !self.cost.sacrifice.is_empty()
+            || !self.cost.discard.is_empty()
+            || !self.cost.other.is_empty()
+    }
+}
+
+/// Whether a card enters the battlefield tapped
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum EntersTapped {
+    Never,
+    Always,
+    /// Only under some condition, e.g. `unless you control two or more other lands`
+    Conditional,
+}
+
+/// The mana a card can produce
+#[derive(Clone, PartialEq, Debug)]
+pub struct ManaProduction {
+    pub abilities: Vec<ManaAbility>,
+    pub enters_tapped: EntersTapped,
+}
+
+impl ManaProduction {
+    /// Returns the colors the card can produce in WUBRG order
+    pub fn colors(&self) -> Vec<ManaColor> {
+        ManaColor::values()
+            .iter()
+            .cloned()
+            .filter(|color| self.produces(*color))
+            .collect()
+    }
+
+    /// Returns true if the card can produce mana of the color
+    pub fn produces(&self, color: ManaColor) -> bool {
+        self.abilities.iter().any(|ability| ability.produces(color))
+    }
+
+    /// Returns true if the card can produce colorless mana
+    pub fn produces_colorless(&self) -> bool {
+        self.abilities.iter().any(|ability| ability.colorless)
+    }
+
+    /// Returns true if the card produces no mana
+    pub fn is_empty(&self) -> bool {
+        self.abilities.is_empty()
+    }
+}
+
+impl CardDetail {
+    /// Analyses which mana the card can produce
+    ///
+    /// Mana abilities in the text, basic land types, fetch lands and rituals are detected.
+    ///
+    /// ```
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// # use mtgapi_client::model::mana::ManaColor;
+    /// # use mtgapi_client::model::production::*;
+    /// let card = |name: &str, subtypes: &[&str], text: &str| CardDetail {
+    ///     name: name.to_string(),
+    ///     subtypes: subtypes.iter().map(|subtype| subtype.to_string()).collect(),
+    ///     text: text.to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let elves = card("Llanowar Elves", &["Elf", "Druid"], "{T}: Add {G}.").mana_production();
+    /// assert_eq!(elves.colors(), vec![ManaColor::Green]);
+    /// assert!(elves.abilities[0].requires_tap());
+    ///
+    /// let sol_ring = card("Sol Ring", &[], "{T}: Add {C}{C}.").mana_production();
+    /// assert!(sol_ring.produces_colorless());
+    /// assert_eq!(sol_ring.abilities[0].amount, 2);
+    ///
+    /// let birds = card("Birds of Paradise", &["Bird"], "Flying\n{T}: Add one mana of any color.").mana_production();
+    /// assert_eq!(birds.colors().len(), 5);
+    ///
+    /// let guildgate = card(
+    ///     "Dimir Guildgate",
+    ///     &["Gate"],
+    ///     "Dimir Guildgate enters the battlefield tapped.\n{T}: Add {U} or {B}.",
+    /// )
+    /// .mana_production();
+    /// assert_eq!(guildgate.colors(), vec![ManaColor::Blue, ManaColor::Black]);
+    /// assert_eq!(guildgate.enters_tapped, EntersTapped::Always);
+    ///
+    /// let forest = card("Breeding Pool", &["Forest", "Island"],
+    ///     "({T}: Add {G} or {U}.)\nAs Breeding Pool enters the battlefield, you may pay 2 life. If you don't, it enters the battlefield tapped.")
+    ///     .mana_production();
+    /// assert_eq!(forest.colors(), vec![ManaColor::Blue, ManaColor::Green]);
+    /// assert_eq!(forest.enters_tapped, EntersTapped::Conditional);
+    ///
+    /// let heath = card(
+    ///     "Windswept Heath",
+    ///     &[],
+    ///     "{T}, Pay 1 life, Sacrifice Windswept Heath: Search your library for a Forest or Plains card, \
+    ///     put it onto the battlefield, then shuffle.",
+    /// )
+    /// .mana_production();
+    /// assert_eq!(heath.colors(), vec![ManaColor::White, ManaColor::Green]);
+    /// assert_eq!(heath.abilities[0].source, ProductionSource::Fetch);
+    /// assert!(heath.abilities[0].requires_other_cost());
+    ///
+    /// let ritual = card("Dark Ritual", &[], "Add {B}{B}{B}.");
+    /// let ritual = CardDetail { types: vec!["Instant".to_string()], ..ritual }.mana_production();
+    /// assert_eq!(ritual.abilities[0].amount, 3);
+    /// assert_eq!(ritual.abilities[0].source, ProductionSource::Spell);
+    /// ```
+    pub fn mana_production(&self) -> ManaProduction {
+        let mut abilities: Vec<ManaAbility> = BASIC_LAND_TYPES
+            .iter()
+            .filter(|(land_type, _)| self.subtypes.iter().any(|subtype| subtype == land_type))
+            .map(|(_, color)| ManaAbility {
+                colors: vec![*color],
+                colorless: false,
+                any_color: false,
+                amount: 1,
+                cost: AbilityCost {
+                    tap: true,
+                    ..Default::default()
+                },
+                source: ProductionSource::BasicLandType,
+            })
+            .collect();
+        let mut enters_tapped = EntersTapped::Never;
+        for ability in self.abilities() {
+            match ability {
+                Ability::Activated { cost, effect } => {
+                    if let Some(ability) =
+                        parse_added_mana(&effect, &cost, ProductionSource::Ability)
+                            .or_else(|| parse_fetch(&effect, &cost))
+                    {
+                        abilities.push(ability);
+                    }
+                }
+                Ability::Spell(effect) => {
+                    let cost = AbilityCost::default();
+                    if let Some(ability) = parse_added_mana(&effect, &cost, ProductionSource::Spell)
+                    {
+                        abilities.push(ability);
+                    }
+                }
+                Ability::Static(text) => {
+                    if let Some(tapped) = parse_enters_tapped(&text) {
+                        enters_tapped = tapped;
+                    }
+                }
+                _ => (),
+            }
+        }
+        ManaProduction {
+            abilities,
+            enters_tapped,
+        }
+    }
+}
+
+/// Parses effects like `Add {G}.`, `Add {R} or {G}.`, `Add {C}{C}.` or `Add one mana of any color.`
+fn parse_added_mana(
+    effect: &str,
+    cost: &AbilityCost,
+    source: ProductionSource,
+) -> Option<ManaAbility> {
+    if !starts_with_ignore_case(effect, "Add ") {
+        return None;
+    }
+    let added = effect["Add ".len()..].split('.').next()?.trim();
+    let mut ability = ManaAbility {
+        colors: Vec::new(),
+        colorless: false,
+        any_color: false,
+        amount: 1,
+        cost: cost.clone(),
+        source,
+    };
+    if added.contains("mana of any") {
+        ability.any_color = true;
+        ability.amount = NUMBERS
+            .iter()
+            .position(|number| starts_with_ignore_case(added, number))
+            .map_or(1, |position| position as u32 + 1);
+        return Some(ability);
+    }
+    let options: Vec<ManaCost> = added
+        .split([',', ' '])
+        .map(str::trim)
+        .filter(|option| option.starts_with('{'))
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+    for option in &options {
+        for symbol in &option.symbols {
+            match symbol {
+                ManaSymbol::Colored(color) if !ability.colors.contains(color) => {
+                    ability.colors.push(*color)
+                }
+                ManaSymbol::Colorless => ability.colorless = true,
+                _ => (),
+            }
+        }
+    }
+    ability.amount = options
+        .iter()
+        .map(|option| option.symbols.len() as u32)
+        .max()?;
+    ability.colors.sort();
+    Some(ability)
+}
+
+/// Parses fetch land effects like `Search your library for a Forest or Plains card`
+fn parse_fetch(effect: &str, cost: &AbilityCost) -> Option<ManaAbility> {
+    if cost.sacrifice.is_empty() || !starts_with_ignore_case(effect, "Search your library for ") {
+        return None;
+    }
+    let searched = effect.split(',').next()?;
+    let colors: Vec<ManaColor> = BASIC_LAND_TYPES
+        .iter()
+        .filter(|(land_type, _)| searched.contains(land_type))
+        .map(|(_, color)| *color)
+        .collect();
+    let any_color = colors.is_empty() && searched.contains("basic land");
+    if colors.is_empty() && !any_color {
+        return None;
+    }
+    Some(ManaAbility {
+        colors,
+        colorless: false,
+        any_color,
+        amount: 1,
+        cost: cost.clone(),
+        source: ProductionSource::Fetch,
+    })
+}
+
+fn parse_enters_tapped(text: &str) -> Option<EntersTapped> {
+    if !text.contains("enters tapped") && !text.contains("enters the battlefield tapped") {
+        return None;
+    }
+    if text.contains("unless") || text.contains("If ") {
+        Some(EntersTapped::Conditional)
+    } else {
+        Some(EntersTapped::Always)
+    }
+}
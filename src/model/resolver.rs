@@ -0,0 +1,257 @@
+use crate::api::util::edit_distance;
+use crate::model::card::CardDetail;
+use std::collections::HashSet;
+
+/// The default maximum number of candidates returned by a resolver
+pub const DEFAULT_MAX_CANDIDATES: usize = 10;
+
+const SPLIT_SEPARATOR: &str = " // ";
+const PARTIAL_CONFIDENCE: f64 = 0.9;
+const FUZZY_CONFIDENCE: f64 = 0.8;
+const FOREIGN_FACTOR: f64 = 0.95;
+const FOLDED: &[(&str, &str)] = &[
+    ("àáâãäå", "a"),
+    ("æ", "ae"),
+    ("ç", "c"),
+    ("èéêë", "e"),
+    ("ìíîï", "i"),
+    ("ñ", "n"),
+    ("òóôõöø", "o"),
+    ("œ", "oe"),
+    ("ùúûü", "u"),
+    ("ýÿ", "y"),
+    ("ß", "ss"),
+];
+
+/// Normalizes a card name for comparisons
+///
+/// The name is lowercased, diacritics are folded, punctuation is dropped
+/// and split card separators like `/` or `//` become ` // `.
+///
+/// ```
+/// # use mtgapi_client::model::resolver::normalize_name;
+/// assert_eq!(normalize_name("Jace, the Mind Sculptor"), "jace the mind sculptor");
+/// assert_eq!(normalize_name("Fire/Ice"), "fire // ice");
+/// assert_eq!(normalize_name("Æther Vial"), "aether vial");
+/// assert_eq!(normalize_name("Lim-Dûl’s Vault"), "lim duls vault");
+/// ```
+pub fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.to_lowercase().chars() {
+        match FOLDED.iter().find(|(accented, _)| accented.contains(c)) {
+            Some((_, folded)) => normalized.push_str(folded),
+            None if c.is_alphanumeric() || c.is_whitespace() || c == '/' => normalized.push(c),
+            None if c == '-' || c == '_' => normalized.push(' '),
+            None => (),
+        }
+    }
+    normalized
+        .split('/')
+        .map(|part| part.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(SPLIT_SEPARATOR)
+}
+
+/// How a candidate matched the input
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum MatchKind {
+    Exact,
+    Partial,
+    Fuzzy,
+}
+
+/// A card the input may refer to
+#[derive(Clone, Debug)]
+pub struct NameCandidate {
+    pub card: CardDetail,
+    pub kind: MatchKind,
+    /// The normalized name that matched, which may be a foreign name
+    pub matched_name: String,
+    /// How likely the input refers to the card, between 0 and 1
+    pub confidence: f64,
+}
+
+/// A local index resolving messy card names to cards
+///
+/// Cards are found by their name, the combined name of split cards and their foreign names.
+/// Exact matches are tried first, then partial and then fuzzy matches.
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::card::{CardDetail, ForeignName};
+/// # use mtgapi_client::model::resolver::*;
+/// let card = |name: &str, names: &[&str]| CardDetail {
+///     name: name.to_string(),
+///     names: names.iter().map(|name| name.to_string()).collect(),
+///     ..Default::default()
+/// };
+/// let bolt = CardDetail {
+///     foreign_names: vec![ForeignName {
+///         name: "Blitzschlag".to_string(),
+///         language: CardLanguage::German,
+///         ..Default::default()
+///     }],
+///     ..card("Lightning Bolt", &[])
+/// };
+/// let index = CardNameIndex::new(vec![
+///     bolt,
+///     card("Lightning Helix", &[]),
+///     card("Fire", &["Fire", "Ice"]),
+///     card("Ice", &["Fire", "Ice"]),
+///     card("Jace, the Mind Sculptor", &[]),
+/// ]);
+///
+/// let candidates = index.resolve("lightning blot");
+/// assert_eq!(candidates[0].card.name, "Lightning Bolt");
+/// assert_eq!(candidates[0].kind, MatchKind::Fuzzy);
+///
+/// assert_eq!(index.resolve("Fire/Ice")[0].card.name, "Fire");
+/// assert_eq!(index.resolve("Jace, the Mind Sculptor")[0].confidence, 1.0);
+/// assert_eq!(index.resolve("blitzschlag")[0].card.name, "Lightning Bolt");
+/// assert_eq!(index.resolve("lightning").len(), 2);
+/// assert!(index.resolve("Counterspell").is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct CardNameIndex {
+    cards: Vec<CardDetail>,
+    /// The names of the indexed cards
+    card_names: HashSet<String>,
+    /// The normalized names with the index of their card and whether they are foreign
+    names: Vec<(String, usize, bool)>,
+    pub max_candidates: usize,
+}
+
+impl Default for CardNameIndex {
+    fn default() -> CardNameIndex {
+        CardNameIndex {
+            cards: Vec::new(),
+            card_names: HashSet::new(),
+            names: Vec::new(),
+            max_candidates: DEFAULT_MAX_CANDIDATES,
+        }
+    }
+}
+
+impl CardNameIndex {
+    /// Indexes the cards, printings with the same name are kept once
+    pub fn new<I>(cards: I) -> CardNameIndex
+    where
+        I: IntoIterator<Item = CardDetail>,
+    {
+        let mut index = CardNameIndex::default();
+        for card in cards {
+            index.insert(card);
+        }
+        index
+    }
+
+    /// Adds the card to the index, unless a card with the same name is indexed already
+    pub fn insert(&mut self, card: CardDetail) {
+        if !self.card_names.insert(card.name.clone()) {
+            return;
+        }
+        let position = self.cards.len();
+        self.names
+            .push((normalize_name(&card.name), position, false));
+        if card.names.len() > 1 && card.names.first() == Some(&card.name) {
+            let combined = card.names.join(SPLIT_SEPARATOR);
+            self.names
+                .push((normalize_name(&combined), position, false));
+        }
+        for foreign_name in &card.foreign_names {
+            self.names
+                .push((normalize_name(&foreign_name.name), position, true));
+        }
+        self.cards.push(card);
+    }
+
+    /// Returns the cards the input may refer to, the most likely first
+    ///
+    /// Partial matches are only tried without exact matches
+    /// and fuzzy matches only without partial matches.
+    pub fn resolve(&self, input: &str) -> Vec<NameCandidate> {
+        let input = normalize_name(input);
+        if input.is_empty() {
+            return Vec::new();
+        }
+        for kind in &[MatchKind::Exact, MatchKind::Partial, MatchKind::Fuzzy] {
+            let candidates = self.candidates(&input, *kind);
+            if !candidates.is_empty() {
+                return candidates;
+            }
+        }
+        Vec::new()
+    }
+
+    /// Returns the number of cards in the index
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns true if the index holds no cards
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Scores every name, keeps the best name of every card and ranks the cards
+    fn candidates(&self, input: &str, kind: MatchKind) -> Vec<NameCandidate> {
+        let mut best: Vec<Option<(f64, &str)>> = vec![None; self.cards.len()];
+        for (name, position, foreign) in &self.names {
+            let confidence = match score(name, input, kind) {
+                Some(confidence) if *foreign => confidence * FOREIGN_FACTOR,
+                Some(confidence) => confidence,
+                None => continue,
+            };
+            let better = match best[*position] {
+                Some((known, _)) => confidence > known,
+                None => true,
+            };
+            if better {
+                best[*position] = Some((confidence, name));
+            }
+        }
+        let mut candidates: Vec<NameCandidate> = best
+            .into_iter()
+            .enumerate()
+            .filter_map(|(position, best)| {
+                best.map(|(confidence, name)| NameCandidate {
+                    card: self.cards[position].clone(),
+                    kind,
+                    matched_name: name.to_string(),
+                    confidence,
+                })
+            })
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.card.name.cmp(&b.card.name))
+        });
+        candidates.truncate(self.max_candidates);
+        candidates
+    }
+}
+
+/// The confidence that the normalized input refers to the normalized name with the kind of match
+fn score(name: &str, input: &str, kind: MatchKind) -> Option<f64> {
+    match kind {
+        MatchKind::Exact if name == input => Some(1.0),
+        MatchKind::Partial if name.contains(input) || input.contains(name) => {
+            let (shorter, longer) = (name.len().min(input.len()), name.len().max(input.len()));
+            Some(PARTIAL_CONFIDENCE * shorter as f64 / longer as f64)
+        }
+        MatchKind::Fuzzy => {
+            let max_distance = std::cmp::max(1, input.chars().count() / 3);
+            let distance = edit_distance(name, input);
+            let length = name.chars().count().max(input.chars().count());
+            if distance <= max_distance {
+                Some(FUZZY_CONFIDENCE * (1.0 - distance as f64 / length as f64))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
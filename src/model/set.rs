@@ -1,3 +1,6 @@
+use crate::api::set::filtertypes::{SetBorder, SetType};
+use crate::model::booster::BoosterTemplate;
+use crate::model::ids::SetCode;
 use chrono::NaiveDate;
 
 ///DTO for the endpoints returning multiple sets
@@ -26,30 +29,43 @@ pub(crate) enum SetDto {
     },
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// A set as returned by the API
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::set::SetDetail;
+/// let set: SetDetail = serde_json::from_str(r#"{
+///     "code": "KTK", "name": "Khans of Tarkir", "type": "expansion", "border": "black",
+///     "releaseDate": "2014-09-26", "booster": [["rare", "mythic rare"], "uncommon", "common"]
+/// }"#).unwrap();
+/// assert_eq!(set.type_, SetType::Expansion);
+/// assert_eq!(set.border, SetBorder::Black);
+/// assert_eq!(set.booster.pack_size(), 3);
+/// ```
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct SetDetail {
-    pub code: String,
+    pub code: SetCode,
     pub name: String,
-    #[serde(rename = "types")]
-    pub type_: String,
+    #[serde(rename = "type")]
+    pub type_: SetType,
     pub block: Option<String>,
     pub gatherer_code: Option<String>,
     pub old_code: Option<String>,
     pub magic_cards_info_code: Option<String>,
     pub release_date: NaiveDate,
-    pub border: String,
+    pub border: SetBorder,
     pub expansion: Option<String>,
     pub online_only: Option<bool>,
     #[serde(default)]
-    pub booster: Vec<Booster>,
+    pub booster: BoosterTemplate,
     #[serde(rename = "mkm_name")]
     pub mkm_name: Option<String>,
     #[serde(rename = "mkm_id")]
     pub mkm_id: Option<u32>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 #[serde(untagged)]
 pub enum Booster {
     Multiple(Vec<String>),
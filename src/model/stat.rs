@@ -0,0 +1,193 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Display;
+
+/// A power, toughness, loyalty or defense value as printed on the card
+///
+/// ```
+/// # use mtgapi_client::model::stat::StatValue;
+/// assert_eq!(StatValue::from("3"), StatValue::Number(3));
+/// assert_eq!(StatValue::from("*"), StatValue::Star);
+/// assert_eq!(StatValue::from("1+*"), StatValue::StarPlus(1));
+/// assert_eq!(StatValue::from("*-1"), StatValue::StarPlus(-1));
+/// assert_eq!(StatValue::from("X"), StatValue::X);
+/// assert_eq!(StatValue::from("?"), StatValue::Other("?".to_string()));
+/// assert_eq!(StatValue::from("+1"), StatValue::Modifier(1));
+/// assert_eq!(StatValue::StarPlus(1).to_string(), "1+*");
+/// assert_eq!(StatValue::Modifier(1).to_string(), "+1");
+///
+/// assert!(StatValue::Number(3) > StatValue::Number(2));
+/// assert_eq!(StatValue::Star.partial_cmp(&StatValue::Number(2)), None);
+/// assert_eq!(StatValue::Star.partial_cmp(&StatValue::Star), Some(std::cmp::Ordering::Equal));
+/// assert_eq!(StatValue::StarPlus(2).value_with(3), Some(5));
+/// assert_eq!(StatValue::StarPlus(1).value_with(i32::MAX), None);
+/// assert_eq!(StatValue::StarSquared.value_with(i32::MAX), None);
+///
+/// let loyalty: StatValue = serde_json::from_str("4").unwrap();
+/// assert_eq!(loyalty, StatValue::Number(4));
+/// let loyalty: StatValue = serde_json::from_str(r#""X""#).unwrap();
+/// assert_eq!(loyalty, StatValue::X);
+/// assert_eq!(serde_json::to_string(&StatValue::Number(4)).unwrap(), "4");
+/// assert_eq!(serde_json::to_string(&StatValue::Modifier(-1)).unwrap(), r#""-1""#);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum StatValue {
+    /// A fixed number, e.g. `3` or `-1`
+    Number(i32),
+    /// A value defined by the card text `*`
+    Star,
+    /// A value defined by the card text plus a fixed number, e.g. `1+*` or `*-1`
+    StarPlus(i32),
+    /// The square of a value defined by the card text `*²`
+    StarSquared,
+    /// A value chosen when the card enters `X`
+    X,
+    /// A number printed with its sign, which modifies another value, e.g. `+1` on augment cards
+    Modifier(i32),
+    /// Any other value, e.g. `?`, `∞` or `1.5`
+    Other(String),
+}
+
+impl StatValue {
+    /// Returns the number if the value is fixed
+    pub fn fixed(&self) -> Option<i32> {
+        match self {
+            StatValue::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the value depends on the game state
+    pub fn is_variable(&self) -> bool {
+        match self {
+            StatValue::Star | StatValue::StarPlus(_) | StatValue::StarSquared | StatValue::X => {
+                true
+            }
+            StatValue::Number(_) | StatValue::Modifier(_) | StatValue::Other(_) => false,
+        }
+    }
+
+    /// Evaluates the value with the specified value for `*` or `X`, `None` on overflow
+    pub fn value_with(&self, variable: i32) -> Option<i32> {
+        match self {
+            StatValue::Number(number) => Some(*number),
+            StatValue::Star | StatValue::X => Some(variable),
+            StatValue::StarPlus(number) => variable.checked_add(*number),
+            StatValue::StarSquared => variable.checked_mul(variable),
+            StatValue::Modifier(_) | StatValue::Other(_) => None,
+        }
+    }
+
+    /// Returns the smallest value possible when `*` and `X` are at least zero
+    pub fn min_value(&self) -> Option<i32> {
+        self.value_with(0)
+    }
+}
+
+impl PartialOrd for StatValue {
+    /// Only fixed numbers can be compared, other values are only equal to themselves
+    fn partial_cmp(&self, other: &StatValue) -> Option<Ordering> {
+        match (self.fixed(), other.fixed()) {
+            (Some(value), Some(other)) => Some(value.cmp(&other)),
+            _ if self == other => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for StatValue {
+    fn from(value: &str) -> StatValue {
+        let value = value.trim();
+        if let Ok(number) = value.parse::<i32>() {
+            if value.starts_with('+') {
+                return StatValue::Modifier(number);
+            }
+            return StatValue::Number(number);
+        }
+        match value.to_uppercase().as_str() {
+            "*" => return StatValue::Star,
+            "*²" | "*^2" => return StatValue::StarSquared,
+            "X" => return StatValue::X,
+            _ => (),
+        }
+        let star_plus = if let Some(number) = value.strip_suffix("+*") {
+            number.trim().parse::<i32>().ok()
+        } else if let Some(number) = value.strip_prefix("*+") {
+            number.trim().parse::<i32>().ok()
+        } else if let Some(number) = value.strip_prefix("*-") {
+            number.trim().parse::<i32>().ok().map(|number| -number)
+        } else {
+            None
+        };
+        match star_plus {
+            Some(number) => StatValue::StarPlus(number),
+            None => StatValue::Other(value.to_string()),
+        }
+    }
+}
+
+impl From<i32> for StatValue {
+    fn from(value: i32) -> StatValue {
+        StatValue::Number(value)
+    }
+}
+
+impl Display for StatValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StatValue::Number(number) => write!(f, "{}", number),
+            StatValue::Star => write!(f, "*"),
+            StatValue::StarPlus(number) if *number < 0 => write!(f, "*-{}", -number),
+            StatValue::StarPlus(number) => write!(f, "{}+*", number),
+            StatValue::StarSquared => write!(f, "*²"),
+            StatValue::X => write!(f, "X"),
+            StatValue::Modifier(number) => write!(f, "{:+}", number),
+            StatValue::Other(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl Serialize for StatValue {
+    /// Serializes fixed numbers as numbers and any other value as printed
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            StatValue::Number(number) => serializer.serialize_i32(*number),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatValue {
+    /// Deserializes the value leniently from numbers and strings
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<StatValue, D::Error> {
+        deserializer.deserialize_any(StatValueVisitor)
+    }
+}
+
+struct StatValueVisitor;
+
+impl<'de> Visitor<'de> for StatValueVisitor {
+    type Value = StatValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a number or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<StatValue, E> {
+        Ok(StatValue::from(value.to_string().as_str()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<StatValue, E> {
+        Ok(StatValue::from(value.to_string().as_str()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<StatValue, E> {
+        Ok(StatValue::from(value.to_string().as_str()))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<StatValue, E> {
+        Ok(StatValue::from(value))
+    }
+}
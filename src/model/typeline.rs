@@ -0,0 +1,323 @@
+use crate::api::card::filtertypes::{CardSuperType, CardType};
+use crate::api::error::MtgApiErrorKind;
+use crate::model::card::CardDetail;
+use failure::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const FACE_SEPARATOR: &str = "//";
+const SUBTYPE_SEPARATORS: &[&str] = &[" — ", " – ", " - ", "—", "–"];
+const MULTI_WORD_SUBTYPES: &[&str] = &["Time Lord"];
+
+const LAND_TYPES: &[&str] = &[
+    "Plains",
+    "Island",
+    "Swamp",
+    "Mountain",
+    "Forest",
+    "Desert",
+    "Gate",
+    "Lair",
+    "Locus",
+    "Mine",
+    "Power-Plant",
+    "Tower",
+    "Urza's",
+    "Cave",
+    "Sphere",
+    "Town",
+];
+const ARTIFACT_TYPES: &[&str] = &[
+    "Attraction",
+    "Blood",
+    "Bobblehead",
+    "Clue",
+    "Contraption",
+    "Equipment",
+    "Food",
+    "Fortification",
+    "Gold",
+    "Incubator",
+    "Junk",
+    "Map",
+    "Powerstone",
+    "Spacecraft",
+    "Treasure",
+    "Vehicle",
+];
+const ENCHANTMENT_TYPES: &[&str] = &[
+    "Aura",
+    "Background",
+    "Cartouche",
+    "Case",
+    "Class",
+    "Curse",
+    "Role",
+    "Room",
+    "Rune",
+    "Saga",
+    "Shard",
+    "Shrine",
+];
+const SPELL_TYPES: &[&str] = &["Adventure", "Arcane", "Lesson", "Omen", "Trap"];
+const BATTLE_TYPES: &[&str] = &["Siege"];
+
+/// What kind of subtype a subtype is
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SubtypeKind {
+    Creature,
+    Land,
+    Artifact,
+    Enchantment,
+    Spell,
+    Planeswalker,
+    Battle,
+    /// A subtype of a type without subtypes, e.g. of a plane
+    Other,
+}
+
+/// A subtype with its kind
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Subtype {
+    pub name: String,
+    pub kind: SubtypeKind,
+}
+
+/// The type line of a single face, e.g. `Legendary Snow Creature — Elf Druid`
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct TypeLineFace {
+    pub supertypes: Vec<CardSuperType>,
+    pub types: Vec<CardType>,
+    pub subtypes: Vec<Subtype>,
+}
+
+impl TypeLineFace {
+    /// Returns the names of the subtypes
+    pub fn subtype_names(&self) -> Vec<&str> {
+        self.subtypes
+            .iter()
+            .map(|subtype| subtype.name.as_str())
+            .collect()
+    }
+
+    fn parse(face: &str) -> Result<TypeLineFace, Error> {
+        let (types, subtypes) = match SUBTYPE_SEPARATORS
+            .iter()
+            .find_map(|separator| face.find(separator).map(|index| (index, separator.len())))
+        {
+            Some((index, length)) => (&face[..index], &face[index + length..]),
+            None => (face, ""),
+        };
+        let mut line = TypeLineFace::default();
+        for word in types.split_whitespace() {
+            match word.parse::<CardSuperType>() {
+                Ok(supertype) => line.supertypes.push(supertype),
+                Err(_) => line.types.push(CardType::from(word)),
+            }
+        }
+        if line.types.is_empty() {
+            return Err(MtgApiErrorKind::InvalidTypeLine {
+                type_line: face.trim().to_string(),
+            }
+            .into());
+        }
+        line.subtypes = split_subtypes(subtypes)
+            .into_iter()
+            .map(|name| Subtype {
+                kind: subtype_kind(&name, &line.types),
+                name,
+            })
+            .collect();
+        Ok(line)
+    }
+}
+
+/// A parsed type line, with one entry per face
+///
+/// ```
+/// # use mtgapi_client::prelude::*;
+/// # use mtgapi_client::model::typeline::*;
+/// let line: TypeLine = "Legendary Snow Creature — Elf Druid".parse().unwrap();
+/// let face = &line.faces[0];
+/// assert_eq!(face.supertypes, vec![CardSuperType::Legendary, CardSuperType::Snow]);
+/// assert_eq!(face.types, vec![CardType::Creature]);
+/// assert_eq!(face.subtype_names(), vec!["Elf", "Druid"]);
+/// assert_eq!(face.subtypes[0].kind, SubtypeKind::Creature);
+///
+/// let line: TypeLine = "Creature - Human Knight // Instant - Adventure".parse().unwrap();
+/// assert_eq!(line.faces.len(), 2);
+/// assert_eq!(line.faces[1].subtypes[0].kind, SubtypeKind::Spell);
+/// assert_eq!(line.to_string(), "Creature — Human Knight // Instant — Adventure");
+///
+/// let line: TypeLine = "Land Creature — Forest Dryad".parse().unwrap();
+/// let kinds: Vec<_> = line.faces[0].subtypes.iter().map(|subtype| subtype.kind).collect();
+/// assert_eq!(kinds, vec![SubtypeKind::Land, SubtypeKind::Creature]);
+///
+/// let line: TypeLine = "Host Creature — Dwarf".parse().unwrap();
+/// assert!(line.faces[0].supertypes.is_empty());
+/// assert_eq!(line.faces[0].types, vec![CardType::Host, CardType::Creature]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Default, Debug)]
+pub struct TypeLine {
+    pub faces: Vec<TypeLineFace>,
+}
+
+impl TypeLine {
+    /// Returns true if any face has the card type
+    pub fn has_type(&self, card_type: &CardType) -> bool {
+        self.faces.iter().any(|face| face.types.contains(card_type))
+    }
+
+    /// Returns true if any face has the supertype
+    pub fn has_supertype(&self, supertype: &CardSuperType) -> bool {
+        self.faces
+            .iter()
+            .any(|face| face.supertypes.contains(supertype))
+    }
+
+    /// Returns true if any face has the subtype, ignoring the case
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        self.faces.iter().any(|face| {
+            face.subtypes
+                .iter()
+                .any(|entry| entry.name.eq_ignore_ascii_case(subtype))
+        })
+    }
+}
+
+impl FromStr for TypeLine {
+    type Err = Error;
+
+    fn from_str(type_line: &str) -> Result<TypeLine, Error> {
+        let faces = type_line
+            .split(FACE_SEPARATOR)
+            .map(TypeLineFace::parse)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(TypeLine { faces })
+    }
+}
+
+impl Display for TypeLineFace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let types: Vec<&str> = self
+            .supertypes
+            .iter()
+            .map(|value| value.as_str())
+            .chain(self.types.iter().map(|value| value.as_str()))
+            .collect();
+        write!(f, "{}", types.join(" "))?;
+        if !self.subtypes.is_empty() {
+            write!(f, " — {}", self.subtype_names().join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for TypeLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, face) in self.faces.iter().enumerate() {
+            if index > 0 {
+                write!(f, " {} ", FACE_SEPARATOR)?;
+            }
+            write!(f, "{}", face)?;
+        }
+        Ok(())
+    }
+}
+
+impl CardDetail {
+    /// Parses the type line of the card
+    ///
+    /// If the card has supertypes, types or subtypes, they have to appear in the type line.
+    ///
+    /// ```
+    /// # use mtgapi_client::prelude::*;
+    /// # use mtgapi_client::model::card::CardDetail;
+    /// let card = CardDetail {
+    ///     type_: "Artifact — Equipment".to_string(),
+    ///     types: vec!["Artifact".to_string()],
+    ///     subtypes: vec!["Equipment".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let line = card.type_line().unwrap();
+    /// assert!(line.has_type(&CardType::Artifact));
+    ///
+    /// let card = CardDetail {
+    ///     type_: "Artifact — Equipment".to_string(),
+    ///     types: vec!["Creature".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// assert!(card.type_line().is_err());
+    /// ```
+    pub fn type_line(&self) -> Result<TypeLine, Error> {
+        let line: TypeLine = self.type_.parse()?;
+        let consistent = self
+            .supertypes
+            .iter()
+            .all(|supertype| line.has_supertype(&CardSuperType::from(supertype.as_str())))
+            && self
+                .types
+                .iter()
+                .all(|card_type| line.has_type(&CardType::from(card_type.as_str())))
+            && self
+                .subtypes
+                .iter()
+                .all(|subtype| line.has_subtype(subtype));
+        if !consistent {
+            return Err(MtgApiErrorKind::InvalidTypeLine {
+                type_line: self.type_.clone(),
+            }
+            .into());
+        }
+        Ok(line)
+    }
+}
+
+fn split_subtypes(subtypes: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut words = subtypes.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        let joined = words.peek().map(|next| format!("{} {}", word, next));
+        match joined {
+            Some(joined) if MULTI_WORD_SUBTYPES.contains(&joined.as_str()) => {
+                words.next();
+                names.push(joined);
+            }
+            _ => names.push(word.to_string()),
+        }
+    }
+    names
+}
+
+fn subtype_kind(subtype: &str, types: &[CardType]) -> SubtypeKind {
+    let known = |list: &[&str]| list.iter().any(|entry| entry.eq_ignore_ascii_case(subtype));
+    let has = |card_type: CardType| types.contains(&card_type);
+    if has(CardType::Land) && known(LAND_TYPES) {
+        SubtypeKind::Land
+    } else if has(CardType::Artifact) && known(ARTIFACT_TYPES) {
+        SubtypeKind::Artifact
+    } else if has(CardType::Enchantment) && known(ENCHANTMENT_TYPES) {
+        SubtypeKind::Enchantment
+    } else if (has(CardType::Instant) || has(CardType::Sorcery)) && known(SPELL_TYPES) {
+        SubtypeKind::Spell
+    } else if has(CardType::Battle) && known(BATTLE_TYPES) {
+        SubtypeKind::Battle
+    } else if has(CardType::Creature) || has(CardType::Kindred) || has(CardType::Tribal) {
+        SubtypeKind::Creature
+    } else if has(CardType::Planeswalker) {
+        SubtypeKind::Planeswalker
+    } else if has(CardType::Land) {
+        SubtypeKind::Land
+    } else if has(CardType::Artifact) {
+        SubtypeKind::Artifact
+    } else if has(CardType::Enchantment) {
+        SubtypeKind::Enchantment
+    } else if has(CardType::Instant) || has(CardType::Sorcery) {
+        SubtypeKind::Spell
+    } else if has(CardType::Battle) {
+        SubtypeKind::Battle
+    } else {
+        SubtypeKind::Other
+    }
+}